@@ -0,0 +1,29 @@
+/// OrgEngine v1.1 — DOT dump
+///
+/// Replays the frozen golden event stream and writes the resulting
+/// structural graph to `org.dot`, so maintainers can run
+/// `dot -Tpng org.dot -o org.png` (or any Graphviz renderer) to
+/// visually diff structural evolution across sequences.
+use std::fs;
+
+use org_engine_replica::engine::OrgEngine;
+use org_engine_replica::events::EventEnvelope;
+use org_engine_replica::viz::to_dot;
+
+fn main() {
+    let json_str = fs::read_to_string("tests/golden/events.json")
+        .expect("Failed to read tests/golden/events.json");
+    let arr: Vec<serde_json::Value> =
+        serde_json::from_str(&json_str).expect("Failed to parse events JSON");
+    let events: Vec<EventEnvelope> = arr.iter().map(|v| EventEnvelope::from_value(v)).collect();
+
+    let mut engine = OrgEngine::new();
+    engine.initialize_state();
+    for evt in &events {
+        engine.apply_event(evt);
+    }
+
+    let dot = to_dot(engine.state());
+    fs::write("org.dot", &dot).expect("Failed to write org.dot");
+    println!("Wrote org.dot ({} bytes)", dot.len());
+}
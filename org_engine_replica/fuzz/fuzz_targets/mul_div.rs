@@ -0,0 +1,68 @@
+//! Property-test fuzzer for `arithmetic`'s fixed-point primitives.
+//!
+//! Every op is checked against a ground-truth `i128` computation, which
+//! cannot overflow for any `i64` input pair — so it's an oracle the
+//! `i64`-narrowing ops can be compared against across the full operand
+//! space. The invariant under test: no fixed-point path here ever
+//! silently wraps — `checked_*` panics exactly when the `i128` result
+//! falls outside `i64` range, `saturating_*` clamps to `i64::MIN`/`MAX`,
+//! and `mul_div` rounds identically to the reference formula.
+#![no_main]
+
+use std::panic;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+use org_engine_replica::arithmetic::{
+    checked_add, checked_mul, fixed_mul, mul_div, saturating_add, saturating_mul, SCALE,
+};
+
+#[derive(Debug, Arbitrary)]
+struct Operands {
+    a: i64,
+    b: i64,
+    denom_seed: i64,
+}
+
+fn fits_i64(x: i128) -> bool {
+    x >= i64::MIN as i128 && x <= i64::MAX as i128
+}
+
+fuzz_target!(|ops: Operands| {
+    let Operands { a, b, denom_seed } = ops;
+
+    // checked_add / checked_mul vs. the i128 oracle.
+    let add_ref = a as i128 + b as i128;
+    match panic::catch_unwind(|| checked_add(a, b)) {
+        Ok(result) => assert!(fits_i64(add_ref) && result as i128 == add_ref),
+        Err(_) => assert!(!fits_i64(add_ref)),
+    }
+
+    let mul_ref = a as i128 * b as i128;
+    match panic::catch_unwind(|| checked_mul(a, b)) {
+        Ok(result) => assert!(fits_i64(mul_ref) && result as i128 == mul_ref),
+        Err(_) => assert!(!fits_i64(mul_ref)),
+    }
+
+    // saturating_add / saturating_mul clamp instead of overflowing.
+    let expected_add = add_ref.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+    assert_eq!(saturating_add(a, b), expected_add);
+
+    let expected_mul = mul_ref.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+    assert_eq!(saturating_mul(a, b), expected_mul);
+
+    // mul_div / fixed_mul vs. round-to-nearest in i128.
+    let denom = if denom_seed == 0 { 1 } else { denom_seed };
+    let div_ref = (a as i128 * b as i128 + denom as i128 / 2) / denom as i128;
+    match panic::catch_unwind(|| mul_div(a, b, denom)) {
+        Ok(result) => assert!(fits_i64(div_ref) && result as i128 == div_ref),
+        Err(_) => assert!(!fits_i64(div_ref)),
+    }
+
+    let fixed_ref = (a as i128 * b as i128 + SCALE as i128 / 2) / SCALE as i128;
+    match panic::catch_unwind(|| fixed_mul(a, b)) {
+        Ok(result) => assert!(fits_i64(fixed_ref) && result as i128 == fixed_ref),
+        Err(_) => assert!(!fits_i64(fixed_ref)),
+    }
+});
@@ -2,10 +2,172 @@
 ///
 /// All numeric values: i64 fixed-point (SCALE = 10_000).
 /// No float. No f64. No f32.
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
 
 /// Fixed-point scale factor. All "real" values are stored as `real * SCALE`.
 pub const SCALE: i64 = 10_000;
 
+/// Overflow behavior for long-running accumulator math — `structural_debt`
+/// and `organizational_capacity_index` — that an adversarial or
+/// long-lived event stream can drive unboundedly. `Checked` (the
+/// default) panics the instant a sum would overflow `i64`, same as
+/// `checked_add`; that's what tests want. `Saturating` clamps at
+/// `i64::MIN`/`i64::MAX` instead, so a production replay keeps running
+/// with a pinned value rather than crashing mid-stream.
+///
+/// Read from `DomainConstants::overflow_policy`, so it is captured in
+/// `OrgState` snapshots and replay reproduces the same truncation
+/// behavior rather than depending on whatever policy the replaying
+/// process happens to be configured with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OverflowPolicy {
+    Checked,
+    Saturating,
+}
+
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::Checked
+    }
+}
+
+impl OverflowPolicy {
+    /// Add two raw `i64`s under this policy.
+    pub fn add(self, a: i64, b: i64) -> i64 {
+        match self {
+            OverflowPolicy::Checked => checked_add(a, b),
+            OverflowPolicy::Saturating => saturating_add(a, b),
+        }
+    }
+
+    /// Parse the wire string used by `initialize_constants` payloads
+    /// (`"checked"` / `"saturating"`). Unrecognized strings keep the
+    /// existing policy, mirroring how every other `initialize_constants`
+    /// field falls back to `old.<field>` on a missing/bad value.
+    pub fn parse(s: &str, fallback: OverflowPolicy) -> OverflowPolicy {
+        match s {
+            "checked" => OverflowPolicy::Checked,
+            "saturating" => OverflowPolicy::Saturating,
+            _ => fallback,
+        }
+    }
+
+    /// Wire string for this policy — inverse of `parse`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OverflowPolicy::Checked => "checked",
+            OverflowPolicy::Saturating => "saturating",
+        }
+    }
+}
+
+/// A fixed-point scalar: an `i64` that is always understood to carry an
+/// implicit `/ SCALE` — analogous to Substrate's `Perbill`/`Permill`
+/// parts-per-N types, except the scale here is parts-per-10 000 rather
+/// than parts-per-billion or parts-per-million. Wrapping the raw integer
+/// makes "did I forget to scale this" a type error instead of a bug that
+/// only shows up as a 10 000x-off value at runtime.
+///
+/// Serializes as the bare underlying integer (`self.raw()`) for wire
+/// compatibility with the existing JSON payload/snapshot format, which
+/// predates this type and already stores `real * SCALE` directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Encode, Decode, TypeInfo)]
+pub struct Scaled(i64);
+
+impl Scaled {
+    /// The additive identity, `0.0000`.
+    pub const ZERO: Scaled = Scaled(0);
+
+    /// Wrap an already-scaled raw integer (i.e. `real * SCALE`). This is
+    /// the inverse of `raw()` and the boundary where wire/JSON `i64`
+    /// values become `Scaled`.
+    pub const fn from_raw(raw: i64) -> Self {
+        Scaled(raw)
+    }
+
+    /// Wrap a whole integer, e.g. `Scaled::from_int(5)` is `5.0000`.
+    /// Panics on overflow, matching `checked_mul`'s contract.
+    pub fn from_int(whole: i64) -> Self {
+        Scaled(checked_mul(whole, SCALE))
+    }
+
+    /// `num / denom`, e.g. `Scaled::from_rational(1, 2)` is `0.5000`.
+    /// Rounds exactly as `mul_div` does.
+    pub fn from_rational(num: i64, denom: i64) -> Self {
+        Scaled(mul_div(num, SCALE, denom))
+    }
+
+    /// The underlying raw integer (`real * SCALE`), for wire encoding or
+    /// mixing into plain-i64 arithmetic that is already scale-aware.
+    pub const fn raw(self) -> i64 {
+        self.0
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Checked addition. Panics on i64 overflow (see `checked_add`).
+    pub fn checked_add(self, other: Scaled) -> Scaled {
+        Scaled(checked_add(self.0, other.0))
+    }
+
+    /// Checked multiplication by a dimensionless integer factor, e.g.
+    /// doubling a `Scaled` debt value. Panics on i64 overflow.
+    pub fn checked_mul_int(self, factor: i64) -> Scaled {
+        Scaled(checked_mul(self.0, factor))
+    }
+
+    /// Saturating addition. Clamps instead of overflowing.
+    pub fn saturating_add(self, other: Scaled) -> Scaled {
+        Scaled(saturating_add(self.0, other.0))
+    }
+
+    /// Add under an explicit `OverflowPolicy` instead of hardcoding
+    /// `checked_add`/`saturating_add` — what `organizational_capacity_index`
+    /// uses so capacity math observes the same policy as debt math.
+    pub fn add_with_policy(self, other: Scaled, policy: OverflowPolicy) -> Scaled {
+        Scaled(policy.add(self.0, other.0))
+    }
+
+    /// Saturating multiplication by a dimensionless integer factor.
+    pub fn saturating_mul_int(self, factor: i64) -> Scaled {
+        Scaled(saturating_mul(self.0, factor))
+    }
+}
+
+impl core::fmt::Display for Scaled {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let whole = self.0 / SCALE;
+        let frac = (self.0 % SCALE).abs();
+        write!(f, "{}.{:04}", whole, frac)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Scaled {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Scaled {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        i64::deserialize(deserializer).map(Scaled)
+    }
+}
+
 
 /// Checked integer addition. Panics on i64 overflow.
 pub fn checked_add(a: i64, b: i64) -> i64 {
@@ -23,6 +185,42 @@ pub fn checked_mul(a: i64, b: i64) -> i64 {
     }
 }
 
+/// Saturating integer addition. Clamps to `i64::MIN`/`i64::MAX` instead
+/// of overflowing — for callers that want a safe fallback rather than a
+/// panic.
+pub fn saturating_add(a: i64, b: i64) -> i64 {
+    a.saturating_add(b)
+}
+
+/// Saturating integer multiplication. Clamps to `i64::MIN`/`i64::MAX`
+/// instead of overflowing.
+pub fn saturating_mul(a: i64, b: i64) -> i64 {
+    a.saturating_mul(b)
+}
+
+/// `a * b / denom`, computed through a widened `i128` intermediate so the
+/// multiply itself cannot overflow: `(a * b + denom / 2) / denom`. For a
+/// non-negative `a * b` and positive `denom` this rounds to nearest (halves
+/// up); otherwise the final division truncates toward zero, e.g.
+/// `mul_div(-3, 1, 4)` is `0`, not `-1`. Panics if the rounded result does not fit back into
+/// `i64`, or if `denom` is zero — the same "never silently wrap" contract
+/// as `checked_add`/`checked_mul`.
+pub fn mul_div(a: i64, b: i64, denom: i64) -> i64 {
+    if denom == 0 {
+        panic!("mul_div: denom must be nonzero");
+    }
+    let result = (a as i128 * b as i128 + denom as i128 / 2) / denom as i128;
+    i64::try_from(result)
+        .unwrap_or_else(|_| panic!("Overflow: mul_div({}, {}, {}) overflows i64", a, b, denom))
+}
+
+/// Fixed-point multiplication: `a * b / SCALE`, rounded to nearest. The
+/// primitive `density`/`structural_debt` ratio math needs, built directly
+/// on `mul_div` so it shares its overflow and rounding guarantees.
+pub fn fixed_mul(a: i64, b: i64) -> i64 {
+    mul_div(a, b, SCALE)
+}
+
 /// Validate that a role ID matches `[a-zA-Z0-9_-]+`. Panics on mismatch.
 pub fn validate_role_id(role_id: &str) {
     if role_id.is_empty() {
@@ -62,6 +260,45 @@ mod tests {
         checked_mul(i64::MAX, 2);
     }
 
+    #[test]
+    fn test_saturating_add_clamps() {
+        assert_eq!(saturating_add(i64::MAX, 1), i64::MAX);
+        assert_eq!(saturating_add(i64::MIN, -1), i64::MIN);
+        assert_eq!(saturating_add(3, 4), 7);
+    }
+
+    #[test]
+    fn test_saturating_mul_clamps() {
+        assert_eq!(saturating_mul(i64::MAX, 2), i64::MAX);
+        assert_eq!(saturating_mul(i64::MIN, 2), i64::MIN);
+        assert_eq!(saturating_mul(3, 4), 12);
+    }
+
+    #[test]
+    fn test_mul_div_rounds_to_nearest() {
+        assert_eq!(mul_div(7, 3, 2), 11); // 21 / 2 = 10.5 -> 11
+        assert_eq!(mul_div(5, 1, 2), 3); // 5 / 2 = 2.5 -> 3
+        assert_eq!(mul_div(4, 1, 2), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "denom must be nonzero")]
+    fn test_mul_div_zero_denom() {
+        mul_div(1, 1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_mul_div_overflow() {
+        mul_div(i64::MAX, i64::MAX, 1);
+    }
+
+    #[test]
+    fn test_fixed_mul() {
+        // 2.0 * 1.5 = 3.0 in SCALE=10_000 fixed point.
+        assert_eq!(fixed_mul(20_000, 15_000), 30_000);
+    }
+
     #[test]
     fn test_validate_role_id_ok() {
         validate_role_id("role_1");
@@ -73,4 +310,33 @@ mod tests {
     fn test_validate_role_id_bad() {
         validate_role_id("role with spaces");
     }
+
+    #[test]
+    fn test_scaled_display() {
+        assert_eq!(Scaled::from_raw(50_000).to_string(), "5.0000");
+        assert_eq!(Scaled::from_raw(0).to_string(), "0.0000");
+        assert_eq!(Scaled::from_raw(-15_000).to_string(), "-1.5000");
+    }
+
+    #[test]
+    fn test_scaled_constructors() {
+        assert_eq!(Scaled::from_int(5), Scaled::from_raw(50_000));
+        assert_eq!(Scaled::from_rational(1, 2), Scaled::from_raw(5_000));
+    }
+
+    #[test]
+    fn test_scaled_arithmetic() {
+        let a = Scaled::from_int(2);
+        let b = Scaled::from_rational(1, 2);
+        assert_eq!(a.checked_add(b), Scaled::from_raw(25_000));
+        assert_eq!(a.checked_mul_int(3), Scaled::from_int(6));
+        assert!(!a.is_negative());
+        assert!(Scaled::from_raw(-1).is_negative());
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow")]
+    fn test_scaled_checked_add_overflow() {
+        Scaled::from_raw(i64::MAX).checked_add(Scaled::from_raw(1));
+    }
 }
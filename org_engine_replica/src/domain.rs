@@ -3,15 +3,34 @@
 /// Pure data. No behaviour, no transition logic.
 /// All numeric values: i64 fixed-point (SCALE = 10_000).
 
+#[cfg(feature = "std")]
 use std::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "serde")]
 use serde::{Serialize, Deserialize};
-use crate::arithmetic::checked_add;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use crate::arithmetic::{OverflowPolicy, Scaled};
 
 // ── Core Domain Types ──────────────────────────────────────────────
+//
+// `Role`, `DependencyEdge`, `ConstraintVector`, `DomainConstants`, and
+// `TransitionResult` also derive `Encode`/`Decode` (SCALE) and
+// `TypeInfo` (scale-info): every field here is already a
+// SCALE-compatible primitive, so the derive is a direct mirror of the
+// serde one (gated behind the `serde` feature — see lib.rs). `OrgState`
+// and `EventEnvelope` are not — `event_history` and `payload` are
+// arbitrary `serde_json::Value`, so their SCALE encodings are
+// hand-written in `scale_codec.rs`.
 
 /// A single organizational role — the causal unit of structure.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct Role {
     pub id: String,
     pub name: String,
@@ -24,8 +43,9 @@ pub struct Role {
 }
 
 /// Directed dependency between two roles.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct DependencyEdge {
     pub from_role_id: String,
     pub to_role_id: String,
@@ -33,67 +53,88 @@ pub struct DependencyEdge {
     pub critical: bool,
 }
 
-/// Resource constraints — int64 fixed-point (real * SCALE).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+/// Resource constraints — fixed-point (`Scaled`, real * SCALE).
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct ConstraintVector {
-    pub capital: i64,        // default 50000 (5.0 * SCALE)
-    pub talent: i64,         // default 50000
-    pub time: i64,           // default 50000
-    pub political_cost: i64, // default 50000
+    pub capital: Scaled,        // default 5.0000
+    pub talent: Scaled,         // default 5.0000
+    pub time: Scaled,           // default 5.0000
+    pub political_cost: Scaled, // default 5.0000
 }
 
 impl Default for ConstraintVector {
     fn default() -> Self {
         Self {
-            capital: 50000,
-            talent: 50000,
-            time: 50000,
-            political_cost: 50000,
+            capital: Scaled::from_raw(50000),
+            talent: Scaled::from_raw(50000),
+            time: Scaled::from_raw(50000),
+            political_cost: Scaled::from_raw(50000),
         }
     }
 }
 
 impl ConstraintVector {
     /// Aggregate capacity index — integer division.
-    /// `(capital + talent + time + political_cost) // 4`
-    pub fn organizational_capacity_index(&self) -> i64 {
-        let total = checked_add(
-            checked_add(self.capital, self.talent),
-            checked_add(self.time, self.political_cost),
-        );
-        total / 4
+    /// `(capital + talent + time + political_cost) // 4`, accumulated
+    /// under `policy` (see `DomainConstants::overflow_policy`).
+    pub fn organizational_capacity_index(&self, policy: OverflowPolicy) -> Scaled {
+        let total = self
+            .capital
+            .add_with_policy(self.talent, policy)
+            .add_with_policy(self.time.add_with_policy(self.political_cost, policy), policy);
+        Scaled::from_raw(total.raw() / 4)
     }
 }
 
 /// All domain thresholds — injected via InitializeConstants event.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct DomainConstants {
     pub differentiation_threshold: i64,
-    pub differentiation_min_capacity: i64,   // 6.0 * SCALE = 60000
+    pub differentiation_min_capacity: Scaled, // default 6.0000
     pub compression_max_combined_responsibilities: i64,
     pub shock_deactivation_threshold: i64,
     pub shock_debt_base_multiplier: i64,
     pub suppressed_differentiation_debt_increment: i64,
+    /// Maximum BFS hop count a shock propagates through `dependencies`
+    /// (treated as undirected) before propagation stops.
+    pub shock_max_propagation_hops: i64,
+    /// Per-hop decay numerator — secondary debt at hop `k` is scaled by
+    /// `(decay_num / decay_den) ^ k`.
+    pub decay_num: i64,
+    /// Per-hop decay denominator.
+    pub decay_den: i64,
+    /// Checked vs. saturating accumulation for `structural_debt` and
+    /// `organizational_capacity_index` — see `arithmetic::OverflowPolicy`.
+    /// Part of `OrgState` via `DomainConstants`, so it is captured in
+    /// snapshots and replay reproduces the same truncation behavior.
+    pub overflow_policy: OverflowPolicy,
 }
 
 impl Default for DomainConstants {
     fn default() -> Self {
         Self {
             differentiation_threshold: 3,
-            differentiation_min_capacity: 60000,
+            differentiation_min_capacity: Scaled::from_raw(60000),
             compression_max_combined_responsibilities: 5,
             shock_deactivation_threshold: 8,
             shock_debt_base_multiplier: 1,
             suppressed_differentiation_debt_increment: 1,
+            shock_max_propagation_hops: 3,
+            decay_num: 1,
+            decay_den: 2,
+            overflow_policy: OverflowPolicy::Checked,
         }
     }
 }
 
 /// Structured, immutable outcome of a state transition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct TransitionResult {
     pub event_type: String,
     pub success: bool,
@@ -108,6 +149,12 @@ pub struct TransitionResult {
     pub target_density: i64,
     pub shock_target: String,
     pub magnitude: i64,
+    /// Number of roles reached by multi-hop shock propagation (excludes
+    /// the directly-shocked `shock_target`).
+    pub reached_role_count: i64,
+    /// Total secondary debt accumulated at each hop distance — index 0
+    /// is hop 1, index 1 is hop 2, and so on.
+    pub hop_debt_breakdown: Vec<i64>,
 }
 
 impl Default for TransitionResult {
@@ -126,13 +173,21 @@ impl Default for TransitionResult {
             target_density: 0,
             shock_target: String::new(),
             magnitude: 0,
+            reached_role_count: 0,
+            hop_debt_breakdown: Vec::new(),
         }
     }
 }
 
 /// Complete organizational state snapshot.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(deny_unknown_fields)]
+///
+/// `event_history` stays `Vec<serde_json::Value>` under every feature
+/// combination — see the crate-level doc comment in lib.rs for why the
+/// `json` feature only gates `EventEnvelope::to_dict`/`from_value` and
+/// not this field's type.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct OrgState {
     pub roles: BTreeMap<String, Role>,
     pub dependencies: Vec<DependencyEdge>,
@@ -7,8 +7,10 @@
 
 use crate::domain::{OrgState, TransitionResult};
 use crate::events::{EventEnvelope, SCHEMA_VERSION};
+use crate::hashing::ChainState;
 use crate::state::create_initial_state;
 use crate::transitions::apply_event as transition_apply;
+use crate::transitions::try_apply_event as transition_apply_try;
 use crate::invariants::validate_invariants;
 
 /// Stateful engine wrapping the pure functional transition layer.
@@ -16,6 +18,7 @@ pub struct OrgEngine {
     state: Option<OrgState>,
     last_sequence: u64,
     constants_initialized: bool,
+    chain: ChainState,
 }
 
 impl OrgEngine {
@@ -25,9 +28,26 @@ impl OrgEngine {
             state: None,
             last_sequence: 0,
             constants_initialized: false,
+            chain: ChainState::genesis(),
         }
     }
 
+    /// Create a new, uninitialized engine with an OpenTelemetry tracer
+    /// installed as the global provider before the first span is emitted.
+    ///
+    /// Telemetry instruments (`telemetry::instruments`) are process-global,
+    /// so this installs `provider` globally and otherwise behaves exactly
+    /// like `new()` — call it once at process startup in place of `new()`
+    /// when you need spans/metrics routed to a specific exporter.
+    #[cfg(feature = "telemetry")]
+    pub fn with_tracer<P>(provider: P) -> Self
+    where
+        P: opentelemetry::trace::TracerProvider + Send + Sync + 'static,
+    {
+        opentelemetry::global::set_tracer_provider(provider);
+        Self::new()
+    }
+
     /// Access the current state (panics if not initialized).
     pub fn state(&self) -> &OrgState {
         self.state
@@ -35,14 +55,45 @@ impl OrgEngine {
             .expect("Engine not initialised — call initialize_state() first")
     }
 
+    /// Resume an engine from an already-validated state taken at
+    /// `last_sequence` (e.g. restored from a snapshot), instead of
+    /// starting from `create_initial_state`. `initialize_constants` is
+    /// already baked into `state`, so `apply_event` must not demand it
+    /// again — this does not change transition or invariant logic, only
+    /// where replay starts from.
+    ///
+    /// The hash chain restarts at genesis: a snapshot carries no record
+    /// of the chain head at its own sequence, so `chain_head()` here only
+    /// covers events applied *after* the resume, not the full log.
+    pub fn resume_from_state(state: OrgState, last_sequence: u64) -> Self {
+        Self {
+            state: Some(state),
+            last_sequence,
+            constants_initialized: true,
+            chain: ChainState::genesis(),
+        }
+    }
+
     /// Create a fresh initial state and store it.
     pub fn initialize_state(&mut self) -> &OrgState {
         self.state = Some(create_initial_state(None, None, None, None, None, None));
         self.last_sequence = 0;
         self.constants_initialized = false;
+        self.chain = ChainState::genesis();
         self.state.as_ref().unwrap()
     }
 
+    /// Current head of the tamper-evident hash chain — folds in every
+    /// event applied so far plus the state each one produced.
+    pub fn chain_head(&self) -> [u8; 32] {
+        self.chain.head
+    }
+
+    /// Sequence number of the last successfully applied event.
+    pub fn last_sequence(&self) -> u64 {
+        self.last_sequence
+    }
+
     /// Apply a single event:
     ///   1. Validate schema version (must be 1)
     ///   2. Validate sequence (strictly increasing, no gaps)
@@ -50,15 +101,35 @@ impl OrgEngine {
     ///   4. Delegate to transitions.apply_event
     ///   5. Validate invariants on new state
     ///   6. Store and return
+    ///
+    /// Under the `telemetry` feature, the whole pipeline runs inside a span
+    /// carrying `sequence`/`logical_time`/`event_type`; any panic below
+    /// (schema, sequence, constants-first, or an invariant failure from
+    /// `invariants.rs`) is recorded as a tagged `[INVARIANT:*]` span event
+    /// before it resumes unwinding — see `telemetry::traced_apply_event`.
     pub fn apply_event(
         &mut self,
         event: &EventEnvelope,
     ) -> (&OrgState, TransitionResult) {
+        #[cfg(feature = "telemetry")]
+        let result = crate::telemetry::traced_apply_event(
+            event.event_type.as_str(),
+            event.sequence,
+            event.logical_time,
+            || self.apply_event_inner(event),
+        );
+        #[cfg(not(feature = "telemetry"))]
+        let result = self.apply_event_inner(event);
+
+        (self.state.as_ref().unwrap(), result)
+    }
+
+    fn apply_event_inner(&mut self, event: &EventEnvelope) -> TransitionResult {
         // -- Schema version enforcement --
         if event.schema_version != SCHEMA_VERSION {
             panic!(
                 "Schema version mismatch: expected {}, got {}. \
-                 Future schema changes require kernel_v2.",
+                 Future schema changes require kernel_v2. [INVARIANT:schema_version]",
                 SCHEMA_VERSION, event.schema_version
             );
         }
@@ -67,7 +138,7 @@ impl OrgEngine {
         let expected = self.last_sequence + 1;
         if event.sequence != expected {
             panic!(
-                "Sequence violation: expected {}, got {}",
+                "Sequence violation: expected {}, got {}. [INVARIANT:sequence]",
                 expected, event.sequence
             );
         }
@@ -76,13 +147,13 @@ impl OrgEngine {
         if !self.constants_initialized {
             if event.event_type != "initialize_constants" {
                 panic!(
-                    "First event MUST be initialize_constants, got {:?}",
+                    "First event MUST be initialize_constants, got {:?}. [INVARIANT:constants_first]",
                     event.event_type
                 );
             }
             self.constants_initialized = true;
         } else if event.event_type == "initialize_constants" {
-            panic!("initialize_constants can only be the first event");
+            panic!("initialize_constants can only be the first event. [INVARIANT:constants_first]");
         }
 
         let current = self
@@ -91,11 +162,73 @@ impl OrgEngine {
             .expect("Engine not initialised — call initialize_state() first");
 
         let (new_state, result) = transition_apply(current, event);
+
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::traced_validate(validate_invariants, &new_state);
+        #[cfg(not(feature = "telemetry"))]
         validate_invariants(&new_state);
+
+        self.chain.advance(event, &new_state);
         self.state = Some(new_state);
         self.last_sequence = event.sequence;
 
-        (self.state.as_ref().unwrap(), result)
+        result
+    }
+
+    /// Non-panicking companion to `apply_event`.
+    ///
+    /// Runs the same schema/sequence/constants-first checks, but returns
+    /// `Err(KernelError)` instead of aborting the process, and delegates
+    /// mutation to `transitions::try_apply_event` so a malformed payload
+    /// surfaces as a structured error. State is left unchanged on error.
+    pub fn try_apply_event(
+        &mut self,
+        event: &EventEnvelope,
+    ) -> Result<(&OrgState, TransitionResult), crate::error::KernelError> {
+        use crate::error::KernelError;
+
+        if event.schema_version != SCHEMA_VERSION {
+            return Err(KernelError::SchemaVersionMismatch {
+                expected: SCHEMA_VERSION,
+                got: event.schema_version,
+            });
+        }
+
+        let expected = self.last_sequence + 1;
+        if event.sequence != expected {
+            return Err(KernelError::SequenceViolation {
+                expected,
+                got: event.sequence,
+            });
+        }
+
+        if !self.constants_initialized {
+            if event.event_type != "initialize_constants" {
+                return Err(KernelError::ConstantsFirstViolation(format!(
+                    "First event MUST be initialize_constants, got {:?}",
+                    event.event_type
+                )));
+            }
+        } else if event.event_type == "initialize_constants" {
+            return Err(KernelError::ConstantsFirstViolation(
+                "initialize_constants can only be the first event".to_string(),
+            ));
+        }
+
+        let current = self
+            .state
+            .as_ref()
+            .expect("Engine not initialised — call initialize_state() first");
+
+        let (new_state, result) = transition_apply_try(current, event)?;
+        crate::invariants::try_validate_invariants(&new_state)
+            .map_err(KernelError::InvariantViolation)?;
+        self.chain.advance(event, &new_state);
+        self.state = Some(new_state);
+        self.last_sequence = event.sequence;
+        self.constants_initialized = true;
+
+        Ok((self.state.as_ref().unwrap(), result))
     }
 
     /// Apply an ordered sequence of events deterministically.
@@ -0,0 +1,93 @@
+/// OrgEngine v1.1 — Typed Kernel Errors
+///
+/// Companion to the panic-based transition API. `transitions::apply_event`
+/// and `engine::OrgEngine::apply_event` remain unchanged (Kernel v1 is
+/// immutable) and still abort the process on a malformed event — but a
+/// server replaying untrusted event streams should not crash on bad
+/// input. `try_apply_event` / `OrgEngine::try_apply_event` thread these
+/// errors out of each handler instead, mirroring the existing
+/// `validate_invariants` / `try_validate_invariants` split in
+/// invariants.rs.
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+/// All recoverable failure modes of a single transition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelError {
+    /// `event_type` does not match any known handler.
+    UnknownEventType(String),
+    /// A role ID collision on `add_role` / `differentiate_role`.
+    RoleCollision(String),
+    /// A referenced role does not exist.
+    MissingRole(String),
+    /// A required payload field was absent or the wrong type.
+    MissingPayloadField { event_type: String, field: String },
+    /// The payload carried a key the schema registry does not declare
+    /// for this event type.
+    UnexpectedPayloadField { event_type: String, field: String },
+    /// Checked arithmetic would overflow `i64`.
+    ConstraintOverflow(String),
+    /// `compress_roles` would exceed `compression_max_combined_responsibilities`.
+    CompressionLimitExceeded { combined: i64, limit: i64 },
+    /// `apply_constraint_change` drove a constraint below zero.
+    NegativeConstraint(String),
+    /// `event.schema_version` did not match `events::SCHEMA_VERSION`.
+    SchemaVersionMismatch { expected: u32, got: u32 },
+    /// `event.sequence` was not `last_sequence + 1`.
+    SequenceViolation { expected: u64, got: u64 },
+    /// The first event applied was not `initialize_constants`, or
+    /// `initialize_constants` was replayed after the first event.
+    ConstantsFirstViolation(String),
+    /// The new state produced by a transition failed
+    /// `invariants::try_validate_invariants`.
+    InvariantViolation(String),
+}
+
+impl fmt::Display for KernelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KernelError::UnknownEventType(t) => write!(f, "Unknown event type: {}", t),
+            KernelError::RoleCollision(id) => {
+                write!(f, "Role ID collision: {:?} already exists", id)
+            }
+            KernelError::MissingRole(id) => write!(f, "Role {:?} does not exist", id),
+            KernelError::MissingPayloadField { event_type, field } => write!(
+                f,
+                "{}: missing {:?} in payload",
+                event_type, field
+            ),
+            KernelError::UnexpectedPayloadField { event_type, field } => write!(
+                f,
+                "{}: unexpected payload field {:?}",
+                event_type, field
+            ),
+            KernelError::ConstraintOverflow(msg) => write!(f, "Overflow: {}", msg),
+            KernelError::CompressionLimitExceeded { combined, limit } => write!(
+                f,
+                "Compression would produce {} responsibilities, \
+                 exceeding compression_max_combined_responsibilities={}",
+                combined, limit
+            ),
+            KernelError::NegativeConstraint(msg) => {
+                write!(f, "Negative constraint overflow detected: {}", msg)
+            }
+            KernelError::SchemaVersionMismatch { expected, got } => write!(
+                f,
+                "Schema version mismatch: expected {}, got {}",
+                expected, got
+            ),
+            KernelError::SequenceViolation { expected, got } => {
+                write!(f, "Sequence violation: expected {}, got {}", expected, got)
+            }
+            KernelError::ConstantsFirstViolation(msg) => write!(f, "{}", msg),
+            KernelError::InvariantViolation(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KernelError {}
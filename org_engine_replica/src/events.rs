@@ -5,6 +5,12 @@
 ///
 /// Schema version is locked at 1. Events with schema_version != 1
 /// are rejected by the engine.
+///
+/// `to_dict`/`from_value` are convenience JSON (de)serialization built
+/// on top of `payload`, which stays `serde_json::Value` regardless of
+/// the `json` feature — see the crate-level doc comment in lib.rs.
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
 
 use serde_json::Value;
 
@@ -22,6 +28,7 @@ pub struct EventEnvelope {
     pub schema_version: u32,
 }
 
+#[cfg(feature = "json")]
 impl EventEnvelope {
     /// Convert to a serde_json::Value matching Python's BaseEvent.to_dict().
     pub fn to_dict(&self) -> Value {
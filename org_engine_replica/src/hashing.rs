@@ -14,6 +14,7 @@ use sha2::{Digest, Sha256};
 use serde_json::{Map, Value};
 
 use crate::domain::OrgState;
+use crate::events::EventEnvelope;
 use crate::KERNEL_VERSION;
 
 /// Canonical serialization of OrgState to UTF-8 JSON bytes.
@@ -37,6 +38,110 @@ pub fn canonical_hash(state: &OrgState) -> String {
         .collect::<String>()
 }
 
+/// Canonical bytes of a single event, for folding into the hash chain.
+/// `to_dict()` builds its object via `serde_json::json!` with a fixed key
+/// list, and `serde_json` writes a `Map` in its own iteration order, so the
+/// same event always yields the same bytes without needing its own
+/// field-order builder.
+fn canonical_event_bytes(event: &EventEnvelope) -> Vec<u8> {
+    serde_json::to_vec(&event.to_dict()).expect("canonical_event_bytes: JSON serialization failed")
+}
+
+/// Seed for a fresh hash chain: binds the chain to the kernel identity so
+/// a log produced under a different `KERNEL_VERSION` can never replay to
+/// a matching head.
+pub fn genesis_chain_head() -> [u8; 32] {
+    let digest = Sha256::digest(KERNEL_VERSION.to_le_bytes());
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&digest);
+    out
+}
+
+/// Running head of a tamper-evident hash chain over an applied event log.
+///
+/// `OrgEngine` advances one `ChainState` per `apply_event` call (see
+/// `engine.rs`), folding in both the event's own bytes and the resulting
+/// state's canonical serialization — so neither replaying events out of order nor
+/// swapping in a tampered state can reproduce the same head.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainState {
+    pub head: [u8; 32],
+    pub height: u64,
+}
+
+impl ChainState {
+    /// A chain with no events folded in yet.
+    pub fn genesis() -> Self {
+        Self {
+            head: genesis_chain_head(),
+            height: 0,
+        }
+    }
+
+    /// Fold `event` and the state it produced into the chain:
+    /// `new_head = SHA256(prev_head || SHA256(event bytes) || canonical_serialize(new_state))`.
+    pub fn advance(&mut self, event: &EventEnvelope, new_state: &OrgState) {
+        let event_hash = Sha256::digest(canonical_event_bytes(event));
+
+        let mut input = Vec::with_capacity(32 + event_hash.len() + 4096);
+        input.extend_from_slice(&self.head);
+        input.extend_from_slice(&event_hash);
+        input.extend_from_slice(&canonical_serialize(new_state));
+
+        let digest = Sha256::digest(&input);
+        self.head.copy_from_slice(&digest);
+        self.height += 1;
+    }
+}
+
+/// Replay `events` from genesis through a fresh `OrgEngine`, recomputing
+/// the hash chain at every step, and compare the final head to
+/// `expected_head`.
+///
+/// Returns `Err` naming the first sequence that breaks the chain: either
+/// the sequence whose `apply_event` call itself panics (a schema,
+/// sequence, constants-first, or invariant violation), or — if replay
+/// completes but the recomputed head still doesn't match
+/// `expected_head` — the final sequence, since a full from-genesis
+/// replay is the only way to know the chain was tampered with once no
+/// panic pinpoints the exact step.
+pub fn verify_chain(
+    events: &[EventEnvelope],
+    expected_head: &[u8; 32],
+) -> Result<(), String> {
+    use std::panic::{self, AssertUnwindSafe};
+
+    let mut engine = crate::engine::OrgEngine::new();
+    engine.initialize_state();
+
+    for event in events {
+        let seq = event.sequence;
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            engine.apply_event(event);
+        }));
+        if let Err(payload) = outcome {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "non-string panic payload".to_string());
+            return Err(format!(
+                "chain diverges at sequence {}: apply_event failed: {}",
+                seq, message
+            ));
+        }
+    }
+
+    if &engine.chain_head() != expected_head {
+        return Err(format!(
+            "chain diverges at sequence {}: recomputed head does not match expected_head",
+            engine.last_sequence()
+        ));
+    }
+
+    Ok(())
+}
+
 /// Build the canonical serde_json::Value in strict field order.
 ///
 /// Uses serde_json::Map which preserves insertion order.
@@ -116,19 +221,19 @@ fn build_canonical_value(state: &OrgState) -> Value {
     let mut cv_map = Map::new();
     cv_map.insert(
         "capital".to_string(),
-        Value::Number(state.constraint_vector.capital.into()),
+        Value::Number(state.constraint_vector.capital.raw().into()),
     );
     cv_map.insert(
         "talent".to_string(),
-        Value::Number(state.constraint_vector.talent.into()),
+        Value::Number(state.constraint_vector.talent.raw().into()),
     );
     cv_map.insert(
         "time".to_string(),
-        Value::Number(state.constraint_vector.time.into()),
+        Value::Number(state.constraint_vector.time.raw().into()),
     );
     cv_map.insert(
         "political_cost".to_string(),
-        Value::Number(state.constraint_vector.political_cost.into()),
+        Value::Number(state.constraint_vector.political_cost.raw().into()),
     );
 
     // -- top-level (strict field order) ---
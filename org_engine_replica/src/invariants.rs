@@ -8,262 +8,323 @@ use crate::domain::OrgState;
 use crate::graph::detect_critical_cycles;
 
 // ---------------------------------------------------------------------------
-// Public API
+// Collect-all-violations linting subsystem
 // ---------------------------------------------------------------------------
 
-/// Run all 7 invariant checks. Panics on the first failure.
-pub fn validate_invariants(state: &OrgState) {
-    check_role_id_format(state);
-    check_dependency_refs(state);
-    check_orphaned_outputs(state);
-    check_duplicate_role_ids(state);
-    check_at_least_one_active_role(state);
-    check_no_empty_responsibilities(state);
-    check_no_critical_cycles(state);
+/// How seriously a `Violation` should be taken. `validate_invariants` /
+/// `try_validate_invariants` only ever fail on `Error`-severity
+/// violations — `Warning` is for rules registered purely for reporting
+/// (e.g. downgrading `orphaned_output` to informational).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
 }
 
-/// Non-panicking variant of `validate_invariants`.
-/// Returns `Err(message)` on the first failure, `Ok(())` if all pass.
-/// Used by snapshot restore to validate without aborting the process.
-pub fn try_validate_invariants(state: &OrgState) -> Result<(), String> {
-    try_check_role_id_format(state)?;
-    try_check_dependency_refs(state)?;
-    try_check_orphaned_outputs(state)?;
-    try_check_duplicate_role_ids(state)?;
-    try_check_at_least_one_active_role(state)?;
-    try_check_no_empty_responsibilities(state)?;
-    try_check_no_critical_cycles(state)?;
-    Ok(())
+/// One violation surfaced by `validate_report`. `code` matches the
+/// `[INVARIANT:*]` tag used throughout this module and in telemetry span
+/// events; `role_id`/`dependency` are populated when the violation
+/// pinpoints a specific entity.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    pub code: &'static str,
+    pub message: String,
+    pub severity: Severity,
+    pub role_id: Option<String>,
+    pub dependency: Option<(String, String)>,
 }
 
-// ---------------------------------------------------------------------------
-// Individual checks (private)
-// ---------------------------------------------------------------------------
-
-/// INV-7: Every role.id must be ASCII [a-zA-Z0-9_-] only.
-fn check_role_id_format(state: &OrgState) {
-    for rid in state.roles.keys() {
-        if rid.is_empty() {
-            panic!(
-                "Invariant violation: [INVARIANT:role_id_format] \
-                 Role ID {:?} contains invalid characters — must match [a-zA-Z0-9_-]+",
-                rid
-            );
-        }
-        for ch in rid.chars() {
-            if !ch.is_ascii_alphanumeric() && ch != '_' && ch != '-' {
-                panic!(
-                    "Invariant violation: [INVARIANT:role_id_format] \
-                     Role ID {:?} contains invalid characters — must match [a-zA-Z0-9_-]+",
-                    rid
-                );
-            }
+impl Violation {
+    fn error(code: &'static str, message: String) -> Self {
+        Self {
+            code,
+            message,
+            severity: Severity::Error,
+            role_id: None,
+            dependency: None,
         }
     }
-}
 
-/// INV-1: Every dependency must reference existing roles.
-fn check_dependency_refs(state: &OrgState) {
-    for dep in &state.dependencies {
-        if !state.roles.contains_key(&dep.from_role_id) {
-            panic!(
-                "Invariant violation: [INVARIANT:dependency_refs] \
-                 Dependency from_role_id={:?} does not exist in roles",
-                dep.from_role_id
-            );
-        }
-        if !state.roles.contains_key(&dep.to_role_id) {
-            panic!(
-                "Invariant violation: [INVARIANT:dependency_refs] \
-                 Dependency to_role_id={:?} does not exist in roles",
-                dep.to_role_id
-            );
-        }
+    fn with_role(mut self, role_id: impl Into<String>) -> Self {
+        self.role_id = Some(role_id.into());
+        self
     }
-}
 
-/// INV-2: Every produced_output must be consumed as a required_input somewhere.
-fn check_orphaned_outputs(state: &OrgState) {
-    let mut all_inputs: BTreeSet<&str> = BTreeSet::new();
-    for role in state.roles.values() {
-        for input in &role.required_inputs {
-            all_inputs.insert(input.as_str());
-        }
+    fn with_dependency(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.dependency = Some((from.into(), to.into()));
+        self
     }
+}
 
-    for role in state.roles.values() {
-        for output in &role.produced_outputs {
-            if !all_inputs.contains(output.as_str()) {
-                panic!(
-                    "Invariant violation: [INVARIANT:orphaned_output] \
-                     Role {:?} produces output {:?} that no role consumes as required_input",
-                    role.id, output
-                );
-            }
-        }
-    }
+/// Outcome of running an `InvariantRegistry` over an `OrgState`: every
+/// violation any registered rule found, in rule-registration order.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub violations: Vec<Violation>,
 }
 
-/// INV-3: No duplicate role IDs.
-fn check_duplicate_role_ids(state: &OrgState) {
-    // BTreeMap cannot have duplicate keys, so this is always satisfied.
-    // Included for completeness to mirror Python.
-    let ids: Vec<&String> = state.roles.keys().collect();
-    let unique: BTreeSet<&String> = ids.iter().cloned().collect();
-    if ids.len() != unique.len() {
-        panic!(
-            "Invariant violation: [INVARIANT:duplicate_role_ids] \
-             Duplicate role IDs detected"
-        );
+impl ValidationReport {
+    pub fn has_errors(&self) -> bool {
+        self.violations.iter().any(|v| v.severity == Severity::Error)
     }
-}
 
-/// INV-4: At least one role must be active (if any roles exist).
-fn check_at_least_one_active_role(state: &OrgState) {
-    if state.roles.is_empty() {
-        return;
+    pub fn errors(&self) -> impl Iterator<Item = &Violation> {
+        self.violations.iter().filter(|v| v.severity == Severity::Error)
     }
-    if !state.roles.values().any(|r| r.active) {
-        panic!(
-            "Invariant violation: [INVARIANT:no_active_roles] \
-             No active roles remain in the organization"
-        );
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Violation> {
+        self.violations.iter().filter(|v| v.severity == Severity::Warning)
     }
 }
 
-/// INV-5: Every role must have at least one responsibility.
-fn check_no_empty_responsibilities(state: &OrgState) {
-    for role in state.roles.values() {
-        if role.responsibilities.is_empty() {
-            panic!(
-                "Invariant violation: [INVARIANT:empty_responsibilities] \
-                 Role {:?} has zero responsibilities",
-                role.id
-            );
+/// A single lintable rule over `OrgState`. The 7 built-in invariants each
+/// implement this; callers can register additional rules (e.g. "no role
+/// depends on an inactive role") without touching the core check list.
+pub trait Invariant {
+    /// Append every violation this rule finds in `state` to `out`. Must
+    /// not stop at the first one — `validate_report` relies on every
+    /// rule being exhaustive so large imported states can be debugged in
+    /// one pass.
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>);
+}
+
+struct RoleIdFormat;
+impl Invariant for RoleIdFormat {
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>) {
+        for rid in state.roles.keys() {
+            let invalid = rid.is_empty()
+                || rid
+                    .chars()
+                    .any(|ch| !ch.is_ascii_alphanumeric() && ch != '_' && ch != '-');
+            if invalid {
+                out.push(
+                    Violation::error(
+                        "role_id_format",
+                        format!(
+                            "Role ID {:?} contains invalid characters — must match [a-zA-Z0-9_-]+",
+                            rid
+                        ),
+                    )
+                    .with_role(rid.clone()),
+                );
+            }
         }
     }
 }
 
-/// INV-6: No cyclic dependency chain where ALL edges are critical=True.
-fn check_no_critical_cycles(state: &OrgState) {
-    let cycles = detect_critical_cycles(state);
-    if !cycles.is_empty() {
-        let cycle_str = cycles[0].join(" -> ");
-        panic!(
-            "Invariant violation: [INVARIANT:critical_cycle] \
-             Critical dependency cycle detected: {}",
-            cycle_str
-        );
+struct DependencyRefs;
+impl Invariant for DependencyRefs {
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>) {
+        for dep in &state.dependencies {
+            if !state.roles.contains_key(&dep.from_role_id) {
+                out.push(
+                    Violation::error(
+                        "dependency_refs",
+                        format!(
+                            "Dependency from_role_id={:?} does not exist in roles",
+                            dep.from_role_id
+                        ),
+                    )
+                    .with_dependency(dep.from_role_id.clone(), dep.to_role_id.clone()),
+                );
+            }
+            if !state.roles.contains_key(&dep.to_role_id) {
+                out.push(
+                    Violation::error(
+                        "dependency_refs",
+                        format!(
+                            "Dependency to_role_id={:?} does not exist in roles",
+                            dep.to_role_id
+                        ),
+                    )
+                    .with_dependency(dep.from_role_id.clone(), dep.to_role_id.clone()),
+                );
+            }
+        }
     }
 }
 
-// ---------------------------------------------------------------------------
-// Non-panicking variants (for snapshot restore)
-// ---------------------------------------------------------------------------
-
-fn try_check_role_id_format(state: &OrgState) -> Result<(), String> {
-    for rid in state.roles.keys() {
-        if rid.is_empty() {
-            return Err(format!(
-                "[INVARIANT:role_id_format] Role ID {:?} is empty — must match [a-zA-Z0-9_-]+",
-                rid
-            ));
+struct OrphanedOutputs;
+impl Invariant for OrphanedOutputs {
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>) {
+        let mut all_inputs: BTreeSet<&str> = BTreeSet::new();
+        for role in state.roles.values() {
+            for input in &role.required_inputs {
+                all_inputs.insert(input.as_str());
+            }
         }
-        for ch in rid.chars() {
-            if !ch.is_ascii_alphanumeric() && ch != '_' && ch != '-' {
-                return Err(format!(
-                    "[INVARIANT:role_id_format] Role ID {:?} contains invalid characters \
-                     — must match [a-zA-Z0-9_-]+",
-                    rid
-                ));
+        for role in state.roles.values() {
+            for output in &role.produced_outputs {
+                if !all_inputs.contains(output.as_str()) {
+                    out.push(
+                        Violation::error(
+                            "orphaned_output",
+                            format!(
+                                "Role {:?} produces output {:?} that no role consumes as required_input",
+                                role.id, output
+                            ),
+                        )
+                        .with_role(role.id.clone()),
+                    );
+                }
             }
         }
     }
-    Ok(())
 }
 
-fn try_check_dependency_refs(state: &OrgState) -> Result<(), String> {
-    for dep in &state.dependencies {
-        if !state.roles.contains_key(&dep.from_role_id) {
-            return Err(format!(
-                "[INVARIANT:dependency_refs] Dependency from_role_id={:?} does not exist in roles",
-                dep.from_role_id
-            ));
-        }
-        if !state.roles.contains_key(&dep.to_role_id) {
-            return Err(format!(
-                "[INVARIANT:dependency_refs] Dependency to_role_id={:?} does not exist in roles",
-                dep.to_role_id
+struct DuplicateRoleIds;
+impl Invariant for DuplicateRoleIds {
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>) {
+        // BTreeMap cannot have duplicate keys, so this is always satisfied.
+        // Included for completeness to mirror Python.
+        let ids: Vec<&String> = state.roles.keys().collect();
+        let unique: BTreeSet<&String> = ids.iter().cloned().collect();
+        if ids.len() != unique.len() {
+            out.push(Violation::error(
+                "duplicate_role_ids",
+                "Duplicate role IDs detected".to_string(),
             ));
         }
     }
-    Ok(())
 }
 
-fn try_check_orphaned_outputs(state: &OrgState) -> Result<(), String> {
-    let mut all_inputs: BTreeSet<&str> = BTreeSet::new();
-    for role in state.roles.values() {
-        for input in &role.required_inputs {
-            all_inputs.insert(input.as_str());
+struct AtLeastOneActiveRole;
+impl Invariant for AtLeastOneActiveRole {
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>) {
+        if state.roles.is_empty() {
+            return;
+        }
+        if !state.roles.values().any(|r| r.active) {
+            out.push(Violation::error(
+                "no_active_roles",
+                "No active roles remain in the organization".to_string(),
+            ));
         }
     }
-    for role in state.roles.values() {
-        for output in &role.produced_outputs {
-            if !all_inputs.contains(output.as_str()) {
-                return Err(format!(
-                    "[INVARIANT:orphaned_output] Role {:?} produces output {:?} \
-                     that no role consumes as required_input",
-                    role.id, output
-                ));
+}
+
+struct NoEmptyResponsibilities;
+impl Invariant for NoEmptyResponsibilities {
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>) {
+        for role in state.roles.values() {
+            if role.responsibilities.is_empty() {
+                out.push(
+                    Violation::error(
+                        "empty_responsibilities",
+                        format!("Role {:?} has zero responsibilities", role.id),
+                    )
+                    .with_role(role.id.clone()),
+                );
             }
         }
     }
-    Ok(())
 }
 
-fn try_check_duplicate_role_ids(state: &OrgState) -> Result<(), String> {
-    let ids: Vec<&String> = state.roles.keys().collect();
-    let unique: BTreeSet<&String> = ids.iter().cloned().collect();
-    if ids.len() != unique.len() {
-        return Err(
-            "[INVARIANT:duplicate_role_ids] Duplicate role IDs detected".to_string()
-        );
+struct NoCriticalCycles;
+impl Invariant for NoCriticalCycles {
+    fn check(&self, state: &OrgState, out: &mut Vec<Violation>) {
+        let cycles = detect_critical_cycles(state);
+        for cycle in &cycles {
+            out.push(Violation::error(
+                "critical_cycle",
+                format!("Critical dependency cycle detected: {}", cycle.join(" -> ")),
+            ));
+        }
     }
-    Ok(())
 }
 
-fn try_check_at_least_one_active_role(state: &OrgState) -> Result<(), String> {
-    if state.roles.is_empty() {
-        return Ok(());
+/// Ordered set of `Invariant` rules to run over an `OrgState`. Seeded with
+/// the 7 built-in checks; callers can `register` extra rules (custom
+/// checks, or built-ins downgraded to `Warning`) without editing this
+/// module.
+pub struct InvariantRegistry {
+    rules: Vec<Box<dyn Invariant>>,
+}
+
+impl InvariantRegistry {
+    /// The 7 built-in invariants, all `Error` severity — matches the
+    /// historical behavior of `validate_invariants`.
+    pub fn default_rules() -> Self {
+        Self {
+            rules: vec![
+                Box::new(RoleIdFormat),
+                Box::new(DependencyRefs),
+                Box::new(OrphanedOutputs),
+                Box::new(DuplicateRoleIds),
+                Box::new(AtLeastOneActiveRole),
+                Box::new(NoEmptyResponsibilities),
+                Box::new(NoCriticalCycles),
+            ],
+        }
     }
-    if !state.roles.values().any(|r| r.active) {
-        return Err(
-            "[INVARIANT:no_active_roles] No active roles remain in the organization".to_string()
-        );
+
+    /// An empty registry, for callers that want full control over which
+    /// rules run (e.g. only the custom ones).
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
     }
-    Ok(())
-}
 
-fn try_check_no_empty_responsibilities(state: &OrgState) -> Result<(), String> {
-    for role in state.roles.values() {
-        if role.responsibilities.is_empty() {
-            return Err(format!(
-                "[INVARIANT:empty_responsibilities] Role {:?} has zero responsibilities",
-                role.id
-            ));
+    pub fn register(&mut self, rule: Box<dyn Invariant>) {
+        self.rules.push(rule);
+    }
+
+    /// Run every registered rule and collect all violations found —
+    /// no rule's failure stops another from running.
+    pub fn run(&self, state: &OrgState) -> ValidationReport {
+        let mut violations = Vec::new();
+        for rule in &self.rules {
+            rule.check(state, &mut violations);
         }
+        ValidationReport { violations }
+    }
+}
+
+/// Run the 7 built-in invariants and return every violation found, each
+/// tagged with its `[INVARIANT:*]` code and severity, instead of
+/// stopping at the first failure. Useful for debugging large imported
+/// states where `validate_invariants`'s fail-fast panic only ever shows
+/// one problem at a time.
+pub fn validate_report(state: &OrgState) -> ValidationReport {
+    InvariantRegistry::default_rules().run(state)
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// Run all 7 invariant checks. Panics on the first `Error`-severity
+/// violation, in the same order they were always checked in.
+///
+/// Delegates to `InvariantRegistry::default_rules`, so this only ever
+/// panics on the built-in Error-severity rules — registering extra
+/// `Warning`-severity rules through a custom registry never affects this
+/// function, which always uses the default set.
+pub fn validate_invariants(state: &OrgState) {
+    let report = validate_report(state);
+    let first_error = report
+        .violations
+        .iter()
+        .find(|v| v.severity == Severity::Error)
+        .map(|v| (v.code, v.message.clone()));
+    if let Some((code, message)) = first_error {
+        panic!("Invariant violation: [INVARIANT:{}] {}", code, message);
     }
-    Ok(())
 }
 
-fn try_check_no_critical_cycles(state: &OrgState) -> Result<(), String> {
-    let cycles = detect_critical_cycles(state);
-    if !cycles.is_empty() {
-        let cycle_str = cycles[0].join(" -> ");
-        return Err(format!(
-            "[INVARIANT:critical_cycle] Critical dependency cycle detected: {}",
-            cycle_str
-        ));
+/// Non-panicking variant of `validate_invariants`.
+/// Returns `Err(message)` for the first `Error`-severity violation,
+/// `Ok(())` if none are found. Used by snapshot restore to validate
+/// without aborting the process.
+pub fn try_validate_invariants(state: &OrgState) -> Result<(), String> {
+    let report = validate_report(state);
+    let first_error = report
+        .violations
+        .iter()
+        .find(|v| v.severity == Severity::Error)
+        .map(|v| (v.code, v.message.clone()));
+    match first_error {
+        Some((code, message)) => Err(format!("[INVARIANT:{}] {}", code, message)),
+        None => Ok(()),
     }
-    Ok(())
 }
+
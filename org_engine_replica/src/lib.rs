@@ -1,14 +1,59 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 
+//! OrgEngine v1.1 — Kernel
+//!
+//! Feature flags (default-on unless noted):
+//!   - `std`: the standard library. Disabling it switches the crate to
+//!     `#![no_std]` + `alloc`, for embedding the core domain types in a
+//!     constrained environment (a wasm guest with no host, firmware).
+//!     Everything that needs real `std` facilities — file/log hashing,
+//!     replay invariants, the SCALE codec, schema validation, telemetry,
+//!     provenance/viz rendering — is gated behind `std` below; only
+//!     `arithmetic`, `domain`, `events`, and `error` remain.
+//!   - `serde`: derives `Serialize`/`Deserialize` on the domain types.
+//!   - `json`: `EventEnvelope::to_dict`/`from_value`. The `payload` and
+//!     `event_history` fields stay `serde_json::Value` regardless of
+//!     this flag — they're load-bearing field types read throughout
+//!     `transitions.rs`'s dispatch, not just JSON convenience wrappers,
+//!     so decomposing them further is out of scope here.
+//!   - `proptest` (off by default): exports `proptest_support`, a set of
+//!     `Strategy`s for generating well-formed domain values and event
+//!     streams, consumed by `tests/proptest_invariants.rs`. Off by
+//!     default because `proptest` is a heavier, test-oriented dependency
+//!     that non-test consumers of this crate shouldn't have to pull in.
+
+extern crate alloc;
+
 /// Kernel v1 — Immutable. Behavioral changes require kernel_v2.
 pub const KERNEL_VERSION: u32 = 1;
 
 pub mod arithmetic;
 pub mod domain;
+pub mod error;
 pub mod events;
-pub mod state;
+
+#[cfg(feature = "std")]
+pub mod engine;
+#[cfg(feature = "std")]
 pub mod graph;
-pub mod transitions;
-pub mod invariants;
+#[cfg(feature = "std")]
 pub mod hashing;
-pub mod engine;
+#[cfg(feature = "std")]
+pub mod invariants;
+#[cfg(feature = "std")]
+pub mod provenance;
+#[cfg(all(feature = "std", feature = "proptest"))]
+pub mod proptest_support;
+#[cfg(feature = "std")]
+pub mod scale_codec;
+#[cfg(feature = "std")]
+pub mod schema;
+#[cfg(feature = "std")]
+pub mod state;
+#[cfg(feature = "std")]
+pub mod telemetry;
+#[cfg(feature = "std")]
+pub mod transitions;
+#[cfg(feature = "std")]
+pub mod viz;
@@ -10,7 +10,69 @@ use org_engine_replica::engine::OrgEngine;
 use org_engine_replica::events::EventEnvelope;
 use org_engine_replica::hashing::canonical_hash;
 
+/// One fixture's outcome, collected for the optional JUnit XML report.
+struct CaseResult {
+    name: String,
+    /// Empty when the fixture passed; one entry per distinct mismatch
+    /// otherwise (hash, determinism, role_count, active_roles,
+    /// structural_debt, or canonical-JSON divergence).
+    failures: Vec<String>,
+}
+
+/// Escape text for inclusion in XML attribute values and element bodies.
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Write a JUnit XML report — one `<testcase>` per fixture, with a
+/// `<failure>` child per distinct mismatch — so CI can ingest per-fixture
+/// results instead of a single process exit code.
+fn write_junit_report(path: &str, cases: &[CaseResult]) -> std::io::Result<()> {
+    let failed = cases.iter().filter(|c| !c.failures.is_empty()).count();
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<testsuite name=\"org_engine_replica.cross_language\" tests=\"{}\" failures=\"{}\">\n",
+        cases.len(),
+        failed
+    ));
+    for case in cases {
+        if case.failures.is_empty() {
+            out.push_str(&format!(
+                "  <testcase name=\"{}\" />\n",
+                xml_escape(&case.name)
+            ));
+        } else {
+            out.push_str(&format!(
+                "  <testcase name=\"{}\">\n",
+                xml_escape(&case.name)
+            ));
+            for failure in &case.failures {
+                out.push_str(&format!(
+                    "    <failure message=\"{}\" />\n",
+                    xml_escape(failure)
+                ));
+            }
+            out.push_str("  </testcase>\n");
+        }
+    }
+    out.push_str("</testsuite>\n");
+
+    fs::write(path, out)
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let junit_path = args
+        .iter()
+        .position(|a| a == "--junit")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+
     // Try to find test_fixtures.json relative to the binary or in the crate root
     let fixture_paths = [
         "test_fixtures.json",
@@ -37,6 +99,7 @@ fn main() {
     let mut all_passed = true;
     let mut total = 0;
     let mut passed = 0;
+    let mut cases: Vec<CaseResult> = Vec::new();
 
     for fixture in &fixtures {
         let seed = fixture["seed"].as_i64().unwrap();
@@ -82,6 +145,32 @@ fn main() {
 
         let ok = hash_match && determ_match && role_match && active_match && debt_match;
 
+        let mut failures: Vec<String> = Vec::new();
+        if !hash_match {
+            failures.push(format!("hash mismatch: rust={} python={}", h1, expected_hash));
+        }
+        if !determ_match {
+            failures.push(format!("determinism fail: run1={} run2={}", h1, h2));
+        }
+        if !role_match {
+            failures.push(format!(
+                "role_count mismatch: rust={} python={}",
+                role_count, expected_role_count
+            ));
+        }
+        if !active_match {
+            failures.push(format!(
+                "active_roles mismatch: rust={} python={}",
+                active_roles, expected_active
+            ));
+        }
+        if !debt_match {
+            failures.push(format!(
+                "structural_debt mismatch: rust={} python={}",
+                structural_debt, expected_debt
+            ));
+        }
+
         if ok {
             passed += 1;
             println!(
@@ -132,13 +221,29 @@ fn main() {
                         &expected_str[..expected_str.len().min(200)]
                     );
                     all_passed = false;
+                    failures.push(format!(
+                        "canonical JSON mismatch: rust={} python={}",
+                        &rust_json[..rust_json.len().min(200)],
+                        &expected_str[..expected_str.len().min(200)]
+                    ));
                 }
             }
         }
+
+        cases.push(CaseResult {
+            name: format!("seed={},n={}", seed, n_events),
+            failures,
+        });
     }
 
     println!("\n===========================================");
     println!("Results: {}/{} passed", passed, total);
+
+    if let Some(path) = &junit_path {
+        write_junit_report(path, &cases).expect("Failed to write JUnit XML report");
+        println!("Wrote JUnit report to: {}", path);
+    }
+
     if all_passed {
         println!("[OK] All cross-language hash checks PASSED.");
     } else {
@@ -0,0 +1,190 @@
+#![cfg(feature = "proptest")]
+//! OrgEngine v1.1 — Property-test generators (optional)
+//!
+//! `Strategy`s for the core domain types, exported so `tests/` can build
+//! generative event streams without re-deriving the wire shape each
+//! handler in `transitions.rs` expects. This is a different `Arbitrary`
+//! from the one the `fuzz/` scaffold uses: `fuzz/fuzz_targets/mul_div.rs`
+//! derives `arbitrary::Arbitrary` for `libfuzzer-sys`'s byte-stream
+//! model, which is the right fit for that target's narrow, numeric
+//! `arithmetic.rs` surface. Generating a *valid* `EventEnvelope`
+//! sequence needs sequencing, id bookkeeping across events, and schema
+//! awareness that a flat byte-stream `Arbitrary` impl doesn't give you
+//! cheaply, so the transition-dispatcher generators here are built on
+//! `proptest::strategy::Strategy` composition instead. Both can still
+//! drive the same `transitions::apply_event`/`try_apply_event` — a
+//! future `arbitrary`-based fuzz target for the dispatcher would reuse
+//! the plain constructor functions below (`role_strategy` and friends
+//! are thin wrappers over them), not duplicate the domain shape.
+
+use proptest::prelude::*;
+
+use crate::domain::{ConstraintVector, DependencyEdge, Role};
+use crate::events::{EventEnvelope, SCHEMA_VERSION};
+
+/// Printable, `validate_role_id`-safe identifier: `[a-z]` followed by up
+/// to 7 `[a-z0-9_]`.
+fn id_strategy() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9_]{0,7}"
+}
+
+prop_compose! {
+    /// A single well-formed `Role`, independent of any `OrgState` it
+    /// might later be inserted into (callers are responsible for id
+    /// uniqueness across a generated set).
+    pub fn role_strategy()(
+        id in id_strategy(),
+        name in "[A-Za-z ]{1,16}",
+        purpose in "[A-Za-z ]{1,32}",
+        responsibilities in prop::collection::vec("[a-z]{1,8}", 1..4),
+        required_inputs in prop::collection::vec("[a-z]{1,8}", 0..4),
+        produced_outputs in prop::collection::vec("[a-z]{1,8}", 0..4),
+        scale_stage in prop::sample::select(vec!["seed", "growth", "structured", "mature"]),
+    ) -> Role {
+        let mut responsibilities = responsibilities;
+        let mut required_inputs = required_inputs;
+        let mut produced_outputs = produced_outputs;
+        responsibilities.sort();
+        required_inputs.sort();
+        produced_outputs.sort();
+        Role {
+            id,
+            name,
+            purpose,
+            responsibilities,
+            required_inputs,
+            produced_outputs,
+            scale_stage: scale_stage.to_string(),
+            active: true,
+        }
+    }
+}
+
+prop_compose! {
+    /// A `DependencyEdge` between two arbitrary (not necessarily
+    /// existing) role ids — callers wire this to roles they generated
+    /// alongside it.
+    pub fn dependency_edge_strategy()(
+        from_role_id in id_strategy(),
+        to_role_id in id_strategy(),
+        dependency_type in prop::sample::select(vec!["operational", "informational", "governance"]),
+        critical in any::<bool>(),
+    ) -> DependencyEdge {
+        DependencyEdge {
+            from_role_id,
+            to_role_id,
+            dependency_type: dependency_type.to_string(),
+            critical,
+        }
+    }
+}
+
+prop_compose! {
+    /// A `ConstraintVector` with each component in `[0, 20.0000)` — wide
+    /// enough to exercise both comfortably-positive and near-zero
+    /// capacity without generating the negative inputs `transitions.rs`
+    /// already rejects before they reach the handlers under test here.
+    pub fn constraint_vector_strategy()(
+        capital in 0i64..200_000,
+        talent in 0i64..200_000,
+        time in 0i64..200_000,
+        political_cost in 0i64..200_000,
+    ) -> ConstraintVector {
+        ConstraintVector {
+            capital: crate::arithmetic::Scaled::from_raw(capital),
+            talent: crate::arithmetic::Scaled::from_raw(talent),
+            time: crate::arithmetic::Scaled::from_raw(time),
+            political_cost: crate::arithmetic::Scaled::from_raw(political_cost),
+        }
+    }
+}
+
+/// Build the mandatory leading `initialize_constants` envelope at
+/// `sequence` 1 — every generated stream must start here, mirroring the
+/// `constants_first` rule `OrgEngine::apply_event` enforces.
+fn initialize_constants_envelope() -> EventEnvelope {
+    EventEnvelope {
+        event_type: "initialize_constants".to_string(),
+        sequence: 1,
+        timestamp: String::new(),
+        logical_time: 0,
+        payload: serde_json::json!({}),
+        schema_version: SCHEMA_VERSION,
+    }
+}
+
+/// An `add_role` envelope for `role` at `sequence`, matching the
+/// payload shape `schema::REGISTRY` declares for `add_role`.
+fn add_role_envelope(role: &Role, sequence: u64) -> EventEnvelope {
+    EventEnvelope {
+        event_type: "add_role".to_string(),
+        sequence,
+        timestamp: String::new(),
+        logical_time: sequence,
+        payload: serde_json::json!({
+            "id": role.id,
+            "name": role.name,
+            "purpose": role.purpose,
+            "responsibilities": role.responsibilities,
+            "required_inputs": role.required_inputs,
+            "produced_outputs": role.produced_outputs,
+            "scale_stage": role.scale_stage,
+        }),
+        schema_version: SCHEMA_VERSION,
+    }
+}
+
+/// An `inject_shock` envelope targeting `role_id` with `magnitude`.
+fn inject_shock_envelope(role_id: &str, magnitude: i64, sequence: u64) -> EventEnvelope {
+    EventEnvelope {
+        event_type: "inject_shock".to_string(),
+        sequence,
+        timestamp: String::new(),
+        logical_time: sequence,
+        payload: serde_json::json!({
+            "target_role_id": role_id,
+            "magnitude": magnitude,
+        }),
+        schema_version: SCHEMA_VERSION,
+    }
+}
+
+prop_compose! {
+    /// A well-formed event stream starting with `initialize_constants`,
+    /// followed by 1-5 `add_role` events with distinct ids, then 0-5
+    /// `inject_shock` events against those roles in round-robin order.
+    /// Every stream this produces is accepted end-to-end by
+    /// `OrgEngine::apply_event` — it exists to drive the cross-cutting
+    /// replay/invariant properties in `tests/proptest_invariants.rs`,
+    /// not to probe malformed-payload rejection (the handlers already
+    /// have unit coverage for that in `transitions.rs`).
+    pub fn event_stream_strategy()(
+        roles in prop::collection::vec(role_strategy(), 1..6),
+        shock_magnitudes in prop::collection::vec(1i64..5_000, 0..6),
+    ) -> Vec<EventEnvelope> {
+        let mut roles = roles;
+        for (i, role) in roles.iter_mut().enumerate() {
+            role.id = format!("{}{}", role.id, i);
+            // Each role consumes its own outputs, so none is orphaned.
+            role.required_inputs.extend(role.produced_outputs.iter().cloned());
+            role.required_inputs.sort();
+            role.required_inputs.dedup();
+        }
+
+        let mut events = vec![initialize_constants_envelope()];
+        // Every magnitude stays below this threshold, so no shock
+        // deactivates a role and the org never runs out of active roles.
+        events[0].payload = serde_json::json!({"shock_deactivation_threshold": 5_000});
+        let mut sequence = 2u64;
+        for role in &roles {
+            events.push(add_role_envelope(role, sequence));
+            sequence += 1;
+        }
+        for (i, magnitude) in shock_magnitudes.iter().enumerate() {
+            let target = &roles[i % roles.len()].id;
+            events.push(inject_shock_envelope(target, *magnitude, sequence));
+            sequence += 1;
+        }
+        events
+    }
+}
@@ -0,0 +1,206 @@
+/// OrgEngine v1.1 — Provenance Graph
+///
+/// `event_history` records events as an opaque list, so there is no way
+/// to ask "which events produced, differentiated, or absorbed this
+/// role?". This module derives a directed provenance graph from an
+/// ordered event stream — nodes for roles and events, edges for
+/// `created_by`, `derived_from` (differentiation parent → children),
+/// `compressed_into` (source → target), and `shocked_by` — and
+/// serializes it to a PROV-JSON-shaped document.
+///
+/// Pure and read-only: it replays the *event* stream for structure, it
+/// never re-runs kernel transition logic and never mutates `OrgState`.
+use std::collections::BTreeSet;
+
+use serde_json::{json, Map, Value};
+
+use crate::events::EventEnvelope;
+
+/// One provenance relation between two nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProvRelation {
+    /// role --created_by--> event
+    CreatedBy,
+    /// child_role --derived_from--> parent_role
+    DerivedFrom,
+    /// source_role --compressed_into--> target_role
+    CompressedInto,
+    /// role --shocked_by--> event
+    ShockedBy,
+}
+
+impl ProvRelation {
+    fn prov_json_key(&self) -> &'static str {
+        match self {
+            ProvRelation::CreatedBy => "wasGeneratedBy",
+            ProvRelation::DerivedFrom => "wasDerivedFrom",
+            ProvRelation::CompressedInto => "wasDerivedFrom",
+            ProvRelation::ShockedBy => "wasInfluencedBy",
+        }
+    }
+}
+
+/// A single directed provenance edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvEdge {
+    pub from: String,
+    pub to: String,
+    pub relation: ProvRelation,
+}
+
+/// Derived provenance graph: role/event node IDs plus directed edges.
+#[derive(Debug, Clone, Default)]
+pub struct ProvenanceGraph {
+    pub role_entities: BTreeSet<String>,
+    pub event_activities: BTreeSet<String>,
+    pub edges: Vec<ProvEdge>,
+}
+
+fn activity_id(event: &EventEnvelope) -> String {
+    format!("event:{}", event.sequence)
+}
+
+fn entity_id(role_id: &str) -> String {
+    format!("role:{}", role_id)
+}
+
+/// Derive a provenance graph by replaying the structural implications of
+/// each event in order. Role IDs are tracked as entities the first time
+/// they appear; every event becomes an activity node even if it has no
+/// provenance edges (e.g. `initialize_constants`).
+pub fn derive_provenance(events: &[EventEnvelope]) -> ProvenanceGraph {
+    let mut graph = ProvenanceGraph::default();
+
+    for event in events {
+        let activity = activity_id(event);
+        graph.event_activities.insert(activity.clone());
+
+        match event.event_type.as_str() {
+            "add_role" => {
+                if let Some(id) = event.payload.get("id").and_then(|v| v.as_str()) {
+                    let role = entity_id(id);
+                    graph.role_entities.insert(role.clone());
+                    graph.edges.push(ProvEdge {
+                        from: role,
+                        to: activity,
+                        relation: ProvRelation::CreatedBy,
+                    });
+                }
+            }
+            "differentiate_role" => {
+                let parent_id = event.payload.get("role_id").and_then(|v| v.as_str());
+                if let Some(parent_id) = parent_id {
+                    let parent = entity_id(parent_id);
+                    graph.role_entities.insert(parent.clone());
+
+                    if let Some(new_roles) = event.payload.get("new_roles").and_then(|v| v.as_array()) {
+                        for nr in new_roles {
+                            if let Some(child_id) = nr.get("id").and_then(|v| v.as_str()) {
+                                let child = entity_id(child_id);
+                                graph.role_entities.insert(child.clone());
+                                graph.edges.push(ProvEdge {
+                                    from: child.clone(),
+                                    to: parent.clone(),
+                                    relation: ProvRelation::DerivedFrom,
+                                });
+                                graph.edges.push(ProvEdge {
+                                    from: child,
+                                    to: activity.clone(),
+                                    relation: ProvRelation::CreatedBy,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            "compress_roles" => {
+                let src = event
+                    .payload
+                    .get("source_role_id")
+                    .and_then(|v| v.as_str());
+                let tgt = event
+                    .payload
+                    .get("target_role_id")
+                    .and_then(|v| v.as_str());
+                if let (Some(src), Some(tgt)) = (src, tgt) {
+                    let source = entity_id(src);
+                    let target = entity_id(tgt);
+                    graph.role_entities.insert(source.clone());
+                    graph.role_entities.insert(target.clone());
+                    graph.edges.push(ProvEdge {
+                        from: source,
+                        to: target.clone(),
+                        relation: ProvRelation::CompressedInto,
+                    });
+                    graph.edges.push(ProvEdge {
+                        from: target,
+                        to: activity,
+                        relation: ProvRelation::CreatedBy,
+                    });
+                }
+            }
+            "inject_shock" => {
+                if let Some(id) = event
+                    .payload
+                    .get("target_role_id")
+                    .and_then(|v| v.as_str())
+                {
+                    let role = entity_id(id);
+                    graph.role_entities.insert(role.clone());
+                    graph.edges.push(ProvEdge {
+                        from: role,
+                        to: activity,
+                        relation: ProvRelation::ShockedBy,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    graph
+}
+
+/// Serialize a `ProvenanceGraph` to a PROV-JSON-shaped document:
+/// top-level `entity`/`activity` maps plus one relation map per PROV
+/// relation kind (`wasGeneratedBy`, `wasDerivedFrom`, `wasInfluencedBy`),
+/// each keyed by a synthetic relation ID. `DerivedFrom` and
+/// `CompressedInto` edges share the `wasDerivedFrom` map.
+pub fn to_prov_json(graph: &ProvenanceGraph) -> Value {
+    let mut entities = Map::new();
+    for e in &graph.role_entities {
+        entities.insert(e.clone(), json!({"prov:type": "role"}));
+    }
+
+    let mut activities = Map::new();
+    for a in &graph.event_activities {
+        activities.insert(a.clone(), json!({}));
+    }
+
+    let mut relations: Map<String, Value> = Map::new();
+    for (i, edge) in graph.edges.iter().enumerate() {
+        let key = edge.relation.prov_json_key();
+        let bucket = relations
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        let (subject_key, object_key) = match edge.relation {
+            ProvRelation::CreatedBy | ProvRelation::ShockedBy => ("prov:entity", "prov:activity"),
+            ProvRelation::DerivedFrom | ProvRelation::CompressedInto => {
+                ("prov:generatedEntity", "prov:usedEntity")
+            }
+        };
+        bucket.as_object_mut().unwrap().insert(
+            format!("_rel{}", i),
+            json!({ subject_key: edge.from, object_key: edge.to }),
+        );
+    }
+
+    let mut root = Map::new();
+    root.insert("prefix".to_string(), json!({"prov": "http://www.w3.org/ns/prov#"}));
+    root.insert("entity".to_string(), Value::Object(entities));
+    root.insert("activity".to_string(), Value::Object(activities));
+    for (k, v) in relations {
+        root.insert(k, v);
+    }
+    Value::Object(root)
+}
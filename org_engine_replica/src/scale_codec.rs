@@ -0,0 +1,148 @@
+/// OrgEngine v1.1 — Deterministic SCALE binary codec
+///
+/// `hashing::canonical_serialize` already pins a deterministic JSON
+/// encoding of `OrgState` (sorted roles/dependencies, fixed field order,
+/// no floats). `parity-scale-codec`'s SCALE format gives the same
+/// determinism guarantee in a compact binary form — fixed integer
+/// width, no map-ordering ambiguity for `OrgState.roles`'s `BTreeMap` —
+/// which is what a byte-stable event log or a cross-implementation
+/// equivalence check against the Python kernel wants instead of JSON.
+///
+/// `Role`, `DependencyEdge`, `ConstraintVector`, `DomainConstants`, and
+/// `TransitionResult` derive `Encode`/`Decode` directly (see domain.rs)
+/// — every field there is already a SCALE-compatible primitive.
+/// `OrgState.event_history` and `EventEnvelope.payload` are both
+/// arbitrary `serde_json::Value`, which SCALE has no native encoding
+/// for, so `OrgState` and `EventEnvelope`'s `Encode`/`Decode` are
+/// hand-written here: each `Value` is carried as its own canonical JSON
+/// bytes, framed like any other SCALE `Vec<u8>`.
+use std::collections::BTreeMap;
+
+use parity_scale_codec::{Decode, Encode, Error, Input, Output};
+use serde_json::Value;
+
+use crate::domain::{ConstraintVector, DomainConstants, OrgState, Role};
+use crate::events::EventEnvelope;
+
+/// Canonical JSON bytes for one `serde_json::Value`. `to_vec` writes each
+/// object's keys in its `serde_json::Map`'s own iteration order (see
+/// `hashing::canonical_event_bytes`), so the same `Value` always encodes
+/// to the same bytes.
+fn encode_value(value: &Value) -> Vec<u8> {
+    serde_json::to_vec(value).expect("scale_codec: JSON value serialization failed")
+}
+
+fn decode_value(bytes: &[u8]) -> Result<Value, Error> {
+    serde_json::from_slice(bytes).map_err(|_| Error::from("scale_codec: invalid JSON value bytes"))
+}
+
+impl Encode for OrgState {
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        self.roles.encode_to(dest);
+        self.dependencies.encode_to(dest);
+        self.constraint_vector.encode_to(dest);
+        self.constants.encode_to(dest);
+        self.scale_stage.encode_to(dest);
+        self.structural_debt.encode_to(dest);
+
+        let history: Vec<Vec<u8>> = self.event_history.iter().map(encode_value).collect();
+        history.encode_to(dest);
+    }
+}
+
+impl Decode for OrgState {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let roles = BTreeMap::<String, Role>::decode(input)?;
+        let dependencies = Vec::decode(input)?;
+        let constraint_vector = ConstraintVector::decode(input)?;
+        let constants = DomainConstants::decode(input)?;
+        let scale_stage = String::decode(input)?;
+        let structural_debt = i64::decode(input)?;
+
+        let history_bytes = Vec::<Vec<u8>>::decode(input)?;
+        let event_history = history_bytes
+            .iter()
+            .map(|bytes| decode_value(bytes))
+            .collect::<Result<Vec<Value>, Error>>()?;
+
+        Ok(OrgState {
+            roles,
+            dependencies,
+            constraint_vector,
+            constants,
+            scale_stage,
+            structural_debt,
+            event_history,
+        })
+    }
+}
+
+impl Encode for EventEnvelope {
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        self.event_type.encode_to(dest);
+        self.sequence.encode_to(dest);
+        self.timestamp.encode_to(dest);
+        self.logical_time.encode_to(dest);
+        encode_value(&self.payload).encode_to(dest);
+        self.schema_version.encode_to(dest);
+    }
+}
+
+impl Decode for EventEnvelope {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let event_type = String::decode(input)?;
+        let sequence = u64::decode(input)?;
+        let timestamp = String::decode(input)?;
+        let logical_time = u64::decode(input)?;
+        let payload = decode_value(&Vec::<u8>::decode(input)?)?;
+        let schema_version = u32::decode(input)?;
+
+        Ok(EventEnvelope {
+            event_type,
+            sequence,
+            timestamp,
+            logical_time,
+            payload,
+            schema_version,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_initial_state;
+
+    #[test]
+    fn org_state_round_trips_through_scale() {
+        let state = create_initial_state(None, None, None, None, None, None);
+        let bytes = state.encode();
+        let decoded = OrgState::decode(&mut &bytes[..]).expect("decode failed");
+        assert_eq!(state.roles.len(), decoded.roles.len());
+        assert_eq!(state.structural_debt, decoded.structural_debt);
+        assert_eq!(state.scale_stage, decoded.scale_stage);
+    }
+
+    #[test]
+    fn event_envelope_round_trips_through_scale() {
+        let event = EventEnvelope {
+            event_type: "add_role".to_string(),
+            sequence: 1,
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            logical_time: 0,
+            payload: serde_json::json!({"id": "r1", "name": "Role", "purpose": "p"}),
+            schema_version: 1,
+        };
+        let bytes = event.encode();
+        let decoded = EventEnvelope::decode(&mut &bytes[..]).expect("decode failed");
+        assert_eq!(event.event_type, decoded.event_type);
+        assert_eq!(event.sequence, decoded.sequence);
+        assert_eq!(event.payload, decoded.payload);
+    }
+
+    #[test]
+    fn encoding_is_deterministic_across_runs() {
+        let state = create_initial_state(None, None, None, None, None, None);
+        assert_eq!(state.encode(), state.clone().encode());
+    }
+}
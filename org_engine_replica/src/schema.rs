@@ -0,0 +1,350 @@
+/// OrgEngine v1.1 — Event Schema Registry
+///
+/// The dispatcher in transitions.rs hardcodes event-type strings and the
+/// payload keys each handler reads. This module is the single source of
+/// truth for that shape: a machine-readable registry of every event type
+/// and its required/optional payload fields, plus `validate_event` so
+/// callers can catch a malformed payload before it reaches a handler's
+/// `.expect()` deep in the dispatch match.
+use crate::events::EventEnvelope;
+use std::fmt;
+
+/// The JSON value kind expected for a payload field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    String,
+    I64,
+    Bool,
+    StringArray,
+    /// A nested object shaped like `add_role`'s payload (used by
+    /// `differentiate_role`'s `new_roles` array).
+    RoleArray,
+}
+
+impl FieldKind {
+    /// Machine-readable token for this kind, used by `registry_json` —
+    /// stable wire vocabulary a non-Rust consumer (e.g. the Python
+    /// harness) can switch on.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldKind::String => "string",
+            FieldKind::I64 => "i64",
+            FieldKind::Bool => "bool",
+            FieldKind::StringArray => "string_array",
+            FieldKind::RoleArray => "role_array",
+        }
+    }
+}
+
+/// One payload field declaration.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldSchema {
+    pub name: &'static str,
+    pub kind: FieldKind,
+    pub required: bool,
+}
+
+const fn field(name: &'static str, kind: FieldKind, required: bool) -> FieldSchema {
+    FieldSchema { name, kind, required }
+}
+
+/// The full declaration for one event type.
+#[derive(Debug, Clone, Copy)]
+pub struct EventSchema {
+    pub event_type: &'static str,
+    pub fields: &'static [FieldSchema],
+}
+
+/// Registry of every event type the kernel dispatcher understands.
+///
+/// This is the one source of truth `validate_event` checks against; keep
+/// it in sync with the `match` in `transitions::apply_event`.
+pub const REGISTRY: &[EventSchema] = &[
+    EventSchema {
+        event_type: "initialize_constants",
+        fields: &[
+            field("differentiation_threshold", FieldKind::I64, false),
+            field("differentiation_min_capacity", FieldKind::I64, false),
+            field(
+                "compression_max_combined_responsibilities",
+                FieldKind::I64,
+                false,
+            ),
+            field("shock_deactivation_threshold", FieldKind::I64, false),
+            field("shock_debt_base_multiplier", FieldKind::I64, false),
+            field(
+                "suppressed_differentiation_debt_increment",
+                FieldKind::I64,
+                false,
+            ),
+            field("shock_max_propagation_hops", FieldKind::I64, false),
+            field("decay_num", FieldKind::I64, false),
+            field("decay_den", FieldKind::I64, false),
+            field("overflow_policy", FieldKind::String, false),
+        ],
+    },
+    EventSchema {
+        event_type: "add_role",
+        fields: &[
+            field("id", FieldKind::String, true),
+            field("name", FieldKind::String, true),
+            field("purpose", FieldKind::String, true),
+            field("responsibilities", FieldKind::StringArray, false),
+            field("required_inputs", FieldKind::StringArray, false),
+            field("produced_outputs", FieldKind::StringArray, false),
+            field("scale_stage", FieldKind::String, false),
+        ],
+    },
+    EventSchema {
+        event_type: "remove_role",
+        fields: &[field("role_id", FieldKind::String, true)],
+    },
+    EventSchema {
+        event_type: "differentiate_role",
+        fields: &[
+            field("role_id", FieldKind::String, true),
+            field("new_roles", FieldKind::RoleArray, true),
+        ],
+    },
+    EventSchema {
+        event_type: "compress_roles",
+        fields: &[
+            field("source_role_id", FieldKind::String, true),
+            field("target_role_id", FieldKind::String, true),
+            field("compressed_name", FieldKind::String, false),
+            field("compressed_purpose", FieldKind::String, false),
+        ],
+    },
+    EventSchema {
+        event_type: "apply_constraint_change",
+        fields: &[
+            field("capital_delta", FieldKind::I64, false),
+            field("talent_delta", FieldKind::I64, false),
+            field("time_delta", FieldKind::I64, false),
+            field("political_cost_delta", FieldKind::I64, false),
+        ],
+    },
+    EventSchema {
+        event_type: "inject_shock",
+        fields: &[
+            field("target_role_id", FieldKind::String, true),
+            field("magnitude", FieldKind::I64, true),
+        ],
+    },
+];
+
+/// Look up the schema for an event type.
+pub fn schema_for(event_type: &str) -> Option<&'static EventSchema> {
+    REGISTRY.iter().find(|s| s.event_type == event_type)
+}
+
+/// List every known event type, for introspection by external clients.
+pub fn known_event_types() -> Vec<&'static str> {
+    REGISTRY.iter().map(|s| s.event_type).collect()
+}
+
+/// Serialize the full registry as JSON: one object per event type with
+/// its declared fields, each field's `kind` (via `FieldKind::as_str`)
+/// and `required`-ness, and the `schema_version` every event on this
+/// wire contract must carry. Downstream tools — notably the Python
+/// harness that must produce payloads shaped exactly like this registry
+/// — can introspect the wire contract from this alone, without reading
+/// the Rust source.
+pub fn registry_json() -> serde_json::Value {
+    serde_json::json!({
+        "schema_version": crate::events::SCHEMA_VERSION,
+        "event_types": REGISTRY
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "event_type": s.event_type,
+                    "fields": s
+                        .fields
+                        .iter()
+                        .map(|f| serde_json::json!({
+                            "name": f.name,
+                            "kind": f.kind.as_str(),
+                            "required": f.required,
+                        }))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// A schema validation failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaError {
+    UnknownEventType(String),
+    MissingField { event_type: String, field: String },
+    WrongType {
+        event_type: String,
+        field: String,
+        expected: &'static str,
+    },
+    /// The payload carried a key the registry does not declare for this
+    /// event type.
+    UnknownField { event_type: String, field: String },
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::UnknownEventType(t) => write!(f, "Unknown event type: {}", t),
+            SchemaError::MissingField { event_type, field } => write!(
+                f,
+                "{}: missing required payload field {:?}",
+                event_type, field
+            ),
+            SchemaError::WrongType {
+                event_type,
+                field,
+                expected,
+            } => write!(
+                f,
+                "{}: payload field {:?} must be {}",
+                event_type, field, expected
+            ),
+            SchemaError::UnknownField { event_type, field } => write!(
+                f,
+                "{}: payload field {:?} is not declared in the schema registry",
+                event_type, field
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+fn matches_kind(value: &serde_json::Value, kind: FieldKind) -> bool {
+    match kind {
+        FieldKind::String => value.is_string(),
+        FieldKind::I64 => value.is_i64() || value.is_u64(),
+        FieldKind::Bool => value.is_boolean(),
+        FieldKind::StringArray => {
+            value.is_array() && value.as_array().unwrap().iter().all(|v| v.is_string())
+        }
+        FieldKind::RoleArray => {
+            value.is_array()
+                && value
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .all(|v| v.is_object() && v.get("id").map(|i| i.is_string()).unwrap_or(false))
+        }
+    }
+}
+
+fn kind_name(kind: FieldKind) -> &'static str {
+    match kind {
+        FieldKind::String => "a string",
+        FieldKind::I64 => "an integer",
+        FieldKind::Bool => "a boolean",
+        FieldKind::StringArray => "an array of strings",
+        FieldKind::RoleArray => "an array of role objects",
+    }
+}
+
+/// Validate an `EventEnvelope`'s payload against the registry.
+///
+/// Checks that `event_type` is known, every required field is present,
+/// and every present field (required or optional) has the declared
+/// JSON kind. Does not run any domain-level validation (role existence,
+/// ID collisions, ...) — that remains the handlers' responsibility.
+pub fn validate_event(event: &EventEnvelope) -> Result<(), SchemaError> {
+    let schema = schema_for(&event.event_type)
+        .ok_or_else(|| SchemaError::UnknownEventType(event.event_type.clone()))?;
+
+    if let Some(obj) = event.payload.as_object() {
+        for key in obj.keys() {
+            if !schema.fields.iter().any(|f| f.name == key.as_str()) {
+                return Err(SchemaError::UnknownField {
+                    event_type: schema.event_type.to_string(),
+                    field: key.clone(),
+                });
+            }
+        }
+    }
+
+    for f in schema.fields {
+        match event.payload.get(f.name) {
+            Some(value) => {
+                if !matches_kind(value, f.kind) {
+                    return Err(SchemaError::WrongType {
+                        event_type: schema.event_type.to_string(),
+                        field: f.name.to_string(),
+                        expected: kind_name(f.kind),
+                    });
+                }
+            }
+            None if f.required => {
+                return Err(SchemaError::MissingField {
+                    event_type: schema.event_type.to_string(),
+                    field: f.name.to_string(),
+                });
+            }
+            None => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope(event_type: &str, payload: serde_json::Value) -> EventEnvelope {
+        EventEnvelope {
+            event_type: event_type.to_string(),
+            sequence: 1,
+            timestamp: String::new(),
+            logical_time: 0,
+            payload,
+            schema_version: crate::events::SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn valid_add_role_passes() {
+        let e = envelope(
+            "add_role",
+            serde_json::json!({"id": "r1", "name": "Role", "purpose": "p"}),
+        );
+        assert!(validate_event(&e).is_ok());
+    }
+
+    #[test]
+    fn missing_required_field_fails() {
+        let e = envelope("add_role", serde_json::json!({"id": "r1"}));
+        assert_eq!(
+            validate_event(&e),
+            Err(SchemaError::MissingField {
+                event_type: "add_role".to_string(),
+                field: "name".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn wrong_type_fails() {
+        let e = envelope(
+            "inject_shock",
+            serde_json::json!({"target_role_id": "r1", "magnitude": "not a number"}),
+        );
+        assert!(matches!(
+            validate_event(&e),
+            Err(SchemaError::WrongType { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_event_type_fails() {
+        let e = envelope("reticulate_splines", serde_json::json!({}));
+        assert_eq!(
+            validate_event(&e),
+            Err(SchemaError::UnknownEventType("reticulate_splines".to_string()))
+        );
+    }
+}
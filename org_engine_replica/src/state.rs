@@ -2,6 +2,7 @@
 ///
 /// All constraint values: int64 fixed-point (real * SCALE).
 
+use crate::arithmetic::Scaled;
 use crate::domain::{ConstraintVector, DomainConstants, OrgState};
 
 /// Create a fresh, empty OrgState with the given constraint defaults.
@@ -17,10 +18,10 @@ pub fn create_initial_state(
         roles: std::collections::BTreeMap::new(),
         dependencies: Vec::new(),
         constraint_vector: ConstraintVector {
-            capital: capital.unwrap_or(50000),
-            talent: talent.unwrap_or(50000),
-            time: time.unwrap_or(50000),
-            political_cost: political_cost.unwrap_or(50000),
+            capital: Scaled::from_raw(capital.unwrap_or(50000)),
+            talent: Scaled::from_raw(talent.unwrap_or(50000)),
+            time: Scaled::from_raw(time.unwrap_or(50000)),
+            political_cost: Scaled::from_raw(political_cost.unwrap_or(50000)),
         },
         constants: constants.unwrap_or_default(),
         scale_stage: scale_stage.unwrap_or("seed").to_string(),
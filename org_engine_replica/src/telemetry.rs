@@ -0,0 +1,174 @@
+#![cfg(feature = "telemetry")]
+//! OrgEngine v1.1 — Telemetry (optional side channel)
+//!
+//! Feature-gated span/metric emission around the transition dispatcher.
+//! This module only *observes* — it never reads or writes `new_state`,
+//! so determinism and the integer-only math in transitions.rs are
+//! preserved regardless of whether the `telemetry` feature is enabled.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use opentelemetry::metrics::{Counter, Gauge, Histogram, Meter};
+use opentelemetry::global;
+use tracing::info_span;
+
+use crate::domain::{OrgState, TransitionResult};
+
+struct Instruments {
+    events_total: Counter<u64>,
+    suppressed_differentiations: Counter<u64>,
+    deactivations: Counter<u64>,
+    structural_debt: Gauge<u64>,
+    last_sequence: Gauge<u64>,
+    transition_latency_ms: Histogram<f64>,
+    invariant_latency_ms: Histogram<f64>,
+}
+
+fn instruments() -> &'static Instruments {
+    static INSTRUMENTS: OnceLock<Instruments> = OnceLock::new();
+    INSTRUMENTS.get_or_init(|| {
+        let meter: Meter = global::meter("org_engine_replica");
+        Instruments {
+            events_total: meter.u64_counter("org_engine.events_total").build(),
+            suppressed_differentiations: meter
+                .u64_counter("org_engine.suppressed_differentiations")
+                .build(),
+            deactivations: meter.u64_counter("org_engine.deactivations").build(),
+            structural_debt: meter.u64_gauge("org_engine.structural_debt").build(),
+            last_sequence: meter.u64_gauge("org_engine.last_sequence").build(),
+            transition_latency_ms: meter
+                .f64_histogram("org_engine.transition_latency_ms")
+                .build(),
+            invariant_latency_ms: meter
+                .f64_histogram("org_engine.invariant_latency_ms")
+                .build(),
+        }
+    })
+}
+
+/// Wrap a single transition dispatch with a span and metric emission.
+///
+/// `apply` is the pure dispatch closure (e.g. the event-type `match` in
+/// `transitions::apply_event`). Its return value passes through
+/// unchanged — telemetry never influences `new_state`.
+pub fn traced_dispatch<F>(event_type: &str, sequence: u64, logical_time: u64, apply: F) -> TransitionResult
+where
+    F: FnOnce() -> TransitionResult,
+{
+    let span = info_span!(
+        "org_engine.apply_event",
+        event_type = %event_type,
+        sequence = sequence,
+        logical_time = logical_time,
+        success = tracing::field::Empty,
+    );
+    let _guard = span.enter();
+
+    let start = Instant::now();
+    let result = apply();
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    span.record("success", result.success);
+
+    let attrs = [opentelemetry::KeyValue::new("event_type", event_type.to_string())];
+    let i = instruments();
+    i.events_total.add(1, &attrs);
+    i.transition_latency_ms.record(elapsed_ms, &attrs);
+    if result.suppressed_differentiation {
+        i.suppressed_differentiations.add(1, &attrs);
+    }
+    if result.deactivated {
+        i.deactivations.add(1, &attrs);
+        tracing::event!(tracing::Level::INFO, role = %result.shock_target, "role deactivated by shock");
+    }
+
+    result
+}
+
+/// Record the post-transition `structural_debt` gauge.
+///
+/// Called after `new_state` is finalized so the gauge reflects the
+/// debt level that resulted from the just-dispatched event.
+pub fn record_structural_debt(structural_debt: i64) {
+    instruments()
+        .structural_debt
+        .record(structural_debt.max(0) as u64, &[]);
+}
+
+/// Wrap a single `OrgEngine::apply_event` call with a span covering the
+/// full schema/sequence/constants/transition/invariant pipeline.
+///
+/// `run` performs the actual dispatch and returns the resulting
+/// `TransitionResult`. If `run` panics (a schema, sequence, constants-first,
+/// or invariant violation — each already tagged `[INVARIANT:*]` in its
+/// panic message by `invariants.rs` or `engine.rs` itself), the tag is
+/// extracted and recorded as a span event before the panic resumes
+/// unwinding, so traces pinpoint which check aborted the event.
+pub fn traced_apply_event<F>(
+    event_type: &str,
+    sequence: u64,
+    logical_time: u64,
+    run: F,
+) -> TransitionResult
+where
+    F: FnOnce() -> TransitionResult,
+{
+    let span = info_span!(
+        "org_engine.apply_event",
+        event_type = %event_type,
+        sequence = sequence,
+        logical_time = logical_time,
+    );
+    let _guard = span.enter();
+
+    match panic::catch_unwind(AssertUnwindSafe(run)) {
+        Ok(result) => {
+            instruments().last_sequence.record(sequence, &[]);
+            result
+        }
+        Err(payload) => {
+            let message = panic_message(&payload);
+            let tag = extract_invariant_tag(&message).unwrap_or("unknown");
+            tracing::event!(
+                tracing::Level::ERROR,
+                tag = tag,
+                message = %message,
+                "[INVARIANT:{}] apply_event aborted",
+                tag
+            );
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// Time a `validate_invariants` call, recording it in the
+/// `org_engine.invariant_latency_ms` histogram regardless of outcome.
+pub fn traced_validate<F>(validate: F, state: &OrgState)
+where
+    F: FnOnce(&OrgState),
+{
+    let start = Instant::now();
+    validate(state);
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+    instruments().invariant_latency_ms.record(elapsed_ms, &[]);
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "non-string panic payload".to_string()
+    }
+}
+
+/// Pull the `check_name` out of a `"... [INVARIANT:check_name] ..."`
+/// panic message, if present.
+fn extract_invariant_tag(message: &str) -> Option<&str> {
+    let start = message.find("[INVARIANT:")? + "[INVARIANT:".len();
+    let end = message[start..].find(']')? + start;
+    Some(&message[start..end])
+}
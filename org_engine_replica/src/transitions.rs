@@ -3,10 +3,19 @@
 /// ALL state-mutation logic lives here.
 /// All math is pure integer. No float. No implicit casting.
 /// Constants read from state.constants (DomainConstants).
+///
+/// This module is `std`-only (see lib.rs) and additionally requires the
+/// `json` feature: it records each applied event into
+/// `OrgState.event_history` via `EventEnvelope::to_dict`, enriched with
+/// the `resulting_debt` that event left behind (see
+/// `record_event_history`). Neither field feeds `hashing::canonical_hash`
+/// (the Kernel v1 identity only covers roles/dependencies/constraints/
+/// structural_debt/scale_stage), so this is free to evolve without
+/// disturbing the golden hash.
 
 use std::collections::BTreeSet;
 
-use crate::arithmetic::{checked_add, checked_mul, validate_role_id};
+use crate::arithmetic::{checked_add, checked_mul, validate_role_id, OverflowPolicy, Scaled};
 use crate::domain::{
     DomainConstants, OrgState, Role, TransitionResult,
 };
@@ -27,7 +36,7 @@ pub fn apply_event(
 
     let etype = event.event_type.as_str();
 
-    let result = match etype {
+    let dispatch = || match etype {
         "initialize_constants" => apply_initialize_constants(&mut new_state, event),
         "add_role" => apply_add_role(&mut new_state, event),
         "remove_role" => apply_remove_role(&mut new_state, event),
@@ -38,8 +47,16 @@ pub fn apply_event(
         _ => panic!("Unknown event type: {}", etype),
     };
 
+    #[cfg(feature = "telemetry")]
+    let result = crate::telemetry::traced_dispatch(etype, event.sequence, event.logical_time, dispatch);
+    #[cfg(not(feature = "telemetry"))]
+    let result = dispatch();
+
     // Record event in history
-    new_state.event_history.push(event.to_dict());
+    record_event_history(&mut new_state, event);
+
+    #[cfg(feature = "telemetry")]
+    crate::telemetry::record_structural_debt(new_state.structural_debt);
 
     (new_state, result)
 }
@@ -63,6 +80,7 @@ fn apply_initialize_constants(
         differentiation_min_capacity: p
             .get("differentiation_min_capacity")
             .and_then(|v| v.as_i64())
+            .map(Scaled::from_raw)
             .unwrap_or(old.differentiation_min_capacity),
         compression_max_combined_responsibilities: p
             .get("compression_max_combined_responsibilities")
@@ -80,6 +98,23 @@ fn apply_initialize_constants(
             .get("suppressed_differentiation_debt_increment")
             .and_then(|v| v.as_i64())
             .unwrap_or(old.suppressed_differentiation_debt_increment),
+        shock_max_propagation_hops: p
+            .get("shock_max_propagation_hops")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.shock_max_propagation_hops),
+        decay_num: p
+            .get("decay_num")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.decay_num),
+        decay_den: p
+            .get("decay_den")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.decay_den),
+        overflow_policy: p
+            .get("overflow_policy")
+            .and_then(|v| v.as_str())
+            .map(|s| OverflowPolicy::parse(s, old.overflow_policy))
+            .unwrap_or(old.overflow_policy),
     };
 
     TransitionResult {
@@ -176,7 +211,9 @@ fn apply_differentiate_role(
     let c = &state.constants;
 
     if (role.responsibilities.len() as i64) > c.differentiation_threshold {
-        let capacity = state.constraint_vector.organizational_capacity_index();
+        let capacity = state
+            .constraint_vector
+            .organizational_capacity_index(c.overflow_policy);
 
         if capacity >= c.differentiation_min_capacity {
             let new_roles_data = p
@@ -240,10 +277,9 @@ fn apply_differentiate_role(
                 ..Default::default()
             };
         } else {
-            state.structural_debt = checked_add(
-                state.structural_debt,
-                c.suppressed_differentiation_debt_increment,
-            );
+            state.structural_debt = c
+                .overflow_policy
+                .add(state.structural_debt, c.suppressed_differentiation_debt_increment);
 
             return TransitionResult {
                 event_type: "differentiate_role".to_string(),
@@ -382,13 +418,19 @@ fn apply_constraint_change(
     let p = &event.payload;
     let cv = &mut state.constraint_vector;
 
-    cv.capital = checked_add(cv.capital, json_i64(p, "capital_delta"));
-    cv.talent = checked_add(cv.talent, json_i64(p, "talent_delta"));
-    cv.time = checked_add(cv.time, json_i64(p, "time_delta"));
-    cv.political_cost = checked_add(cv.political_cost, json_i64(p, "political_cost_delta"));
+    cv.capital = cv.capital.checked_add(Scaled::from_raw(json_i64(p, "capital_delta")));
+    cv.talent = cv.talent.checked_add(Scaled::from_raw(json_i64(p, "talent_delta")));
+    cv.time = cv.time.checked_add(Scaled::from_raw(json_i64(p, "time_delta")));
+    cv.political_cost = cv
+        .political_cost
+        .checked_add(Scaled::from_raw(json_i64(p, "political_cost_delta")));
 
     // Guard: no negative constraints
-    if cv.capital < 0 || cv.talent < 0 || cv.time < 0 || cv.political_cost < 0 {
+    if cv.capital.is_negative()
+        || cv.talent.is_negative()
+        || cv.time.is_negative()
+        || cv.political_cost.is_negative()
+    {
         panic!("Negative constraint overflow detected");
     }
 
@@ -429,7 +471,7 @@ fn apply_inject_shock(
         checked_add(c.shock_debt_base_multiplier, target_density),
     );
     primary_debt = primary_debt.max(1);
-    state.structural_debt = checked_add(state.structural_debt, primary_debt);
+    state.structural_debt = c.overflow_policy.add(state.structural_debt, primary_debt);
 
     // Deactivate if magnitude exceeds threshold
     let mut deactivated = false;
@@ -438,26 +480,20 @@ fn apply_inject_shock(
         deactivated = true;
     }
 
-    // Propagate to connected roles
-    let mut connected_ids: BTreeSet<String> = BTreeSet::new();
-    for dep in &original_state.dependencies {
-        if dep.from_role_id == target_id {
-            connected_ids.insert(dep.to_role_id.clone());
-        } else if dep.to_role_id == target_id {
-            connected_ids.insert(dep.from_role_id.clone());
-        }
-    }
-
-    let mut secondary_debt: i64 = 0;
-    for cid in &connected_ids {
-        if state.roles.contains_key(cid) {
-            let d = compute_role_structural_density(cid, original_state);
-            let inc = checked_mul(magnitude, d).max(1);
-            secondary_debt = checked_add(secondary_debt, inc);
-        }
-    }
+    // Multi-hop propagation — bounded BFS over `dependencies` treated as
+    // undirected, with per-hop fixed-point decay.
+    let adjacency = undirected_adjacency(original_state);
+    let (secondary_debt, reached_role_count, hop_debt_breakdown) = propagate_shock_bfs(
+        &target_id,
+        magnitude,
+        c.shock_max_propagation_hops,
+        c.decay_num,
+        c.decay_den,
+        &adjacency,
+        original_state,
+    );
 
-    state.structural_debt = checked_add(state.structural_debt, secondary_debt);
+    state.structural_debt = c.overflow_policy.add(state.structural_debt, secondary_debt);
 
     TransitionResult {
         event_type: "inject_shock".to_string(),
@@ -468,10 +504,106 @@ fn apply_inject_shock(
         primary_debt,
         secondary_debt,
         target_density,
+        reached_role_count,
+        hop_debt_breakdown,
         ..Default::default()
     }
 }
 
+/// Build an undirected adjacency list (sorted neighbor order) from
+/// `state.dependencies`, for deterministic BFS traversal.
+fn undirected_adjacency(state: &OrgState) -> std::collections::BTreeMap<String, BTreeSet<String>> {
+    let mut adj: std::collections::BTreeMap<String, BTreeSet<String>> =
+        std::collections::BTreeMap::new();
+    for dep in &state.dependencies {
+        adj.entry(dep.from_role_id.clone())
+            .or_default()
+            .insert(dep.to_role_id.clone());
+        adj.entry(dep.to_role_id.clone())
+            .or_default()
+            .insert(dep.from_role_id.clone());
+    }
+    adj
+}
+
+/// Bounded BFS shock propagation with integer fixed-point distance decay.
+///
+/// For each newly-reached role at hop `k`, `inc = magnitude * density *
+/// decay_num^k / decay_den^k` (integer division) is added to
+/// `secondary_debt` — unless that decayed value rounds below 1, in which
+/// case nothing is added and that path stops expanding. Traversal is over sorted neighbor sets, so the
+/// result is fully deterministic. Panics on `i64` overflow (mirrors the
+/// checked arithmetic used throughout the dispatcher).
+fn propagate_shock_bfs(
+    target_id: &str,
+    magnitude: i64,
+    max_hops: i64,
+    decay_num: i64,
+    decay_den: i64,
+    adjacency: &std::collections::BTreeMap<String, BTreeSet<String>>,
+    original_state: &OrgState,
+) -> (i64, i64, Vec<i64>) {
+    use std::collections::VecDeque;
+
+    let mut visited: BTreeSet<String> = BTreeSet::new();
+    visited.insert(target_id.to_string());
+
+    let mut queue: VecDeque<(String, i64)> = VecDeque::new();
+    queue.push_back((target_id.to_string(), 0));
+
+    let mut secondary_debt: i64 = 0;
+    let mut reached_role_count: i64 = 0;
+    let mut hop_debt_breakdown: Vec<i64> = Vec::new();
+
+    while let Some((node, depth)) = queue.pop_front() {
+        if depth >= max_hops {
+            continue;
+        }
+        let neighbors = match adjacency.get(&node) {
+            Some(n) => n.clone(),
+            None => continue,
+        };
+        for nbr in neighbors {
+            if visited.contains(&nbr) {
+                continue;
+            }
+            visited.insert(nbr.clone());
+            let hop = depth + 1;
+
+            if original_state.roles.contains_key(&nbr) {
+                let density = compute_role_structural_density(&nbr, original_state);
+                let mut numerator = checked_mul(magnitude, density);
+                for _ in 0..hop {
+                    numerator = checked_mul(numerator, decay_num);
+                }
+                let mut denominator: i64 = 1;
+                for _ in 0..hop {
+                    denominator = checked_mul(denominator, decay_den);
+                }
+                let inc_raw = numerator / denominator.max(1);
+
+                if inc_raw < 1 {
+                    // Decayed below a visible unit of debt — stop
+                    // expanding along this path.
+                    continue;
+                }
+
+                secondary_debt = checked_add(secondary_debt, inc_raw);
+                reached_role_count = checked_add(reached_role_count, 1);
+                let idx = (hop - 1) as usize;
+                while hop_debt_breakdown.len() <= idx {
+                    hop_debt_breakdown.push(0);
+                }
+                hop_debt_breakdown[idx] = checked_add(hop_debt_breakdown[idx], inc_raw);
+            }
+
+            queue.push_back((nbr, hop));
+        }
+    }
+
+    (secondary_debt, reached_role_count, hop_debt_breakdown)
+}
+
 // ---------------------------------------------------------------------------
 // Helper: extract JSON fields
 // ---------------------------------------------------------------------------
@@ -490,3 +622,606 @@ fn json_str_array(v: &serde_json::Value, key: &str) -> Vec<String> {
 fn json_i64(v: &serde_json::Value, key: &str) -> i64 {
     v.get(key).and_then(|val| val.as_i64()).unwrap_or(0)
 }
+
+// ---------------------------------------------------------------------------
+// Typed Result API (non-panicking companion to the dispatcher above)
+// ---------------------------------------------------------------------------
+//
+// Mirrors the transitions of `apply_event` / the individual handlers,
+// but first checks the payload against `schema::REGISTRY` (so an
+// unknown or wrongly-typed field that `apply_event` would ignore is an
+// error here), and surfaces malformed payloads, ID collisions, missing roles, and overflow as a
+// `KernelError` instead of aborting the process. Kept as a separate,
+// fully-duplicated path (same convention as `try_validate_invariants` in
+// invariants.rs) so the panicking API above — Kernel v1 — is untouched.
+
+use crate::error::KernelError;
+
+/// Apply *event* to *state*, returning `Err(KernelError)` instead of
+/// panicking on malformed payloads, ID collisions, missing roles, or
+/// constraint overflow.
+pub fn try_apply_event(
+    state: &OrgState,
+    event: &EventEnvelope,
+) -> Result<(OrgState, TransitionResult), KernelError> {
+    crate::schema::validate_event(event).map_err(|e| match e {
+        crate::schema::SchemaError::UnknownEventType(t) => KernelError::UnknownEventType(t),
+        crate::schema::SchemaError::MissingField { event_type, field } => {
+            KernelError::MissingPayloadField { event_type, field }
+        }
+        crate::schema::SchemaError::WrongType { event_type, field, .. } => {
+            KernelError::MissingPayloadField { event_type, field }
+        }
+        crate::schema::SchemaError::UnknownField { event_type, field } => {
+            KernelError::UnexpectedPayloadField { event_type, field }
+        }
+    })?;
+
+    let mut new_state = state.clone();
+    let etype = event.event_type.as_str();
+
+    let result = match etype {
+        "initialize_constants" => try_apply_initialize_constants(&mut new_state, event),
+        "add_role" => try_apply_add_role(&mut new_state, event),
+        "remove_role" => try_apply_remove_role(&mut new_state, event),
+        "differentiate_role" => try_apply_differentiate_role(&mut new_state, event),
+        "compress_roles" => try_apply_compress_roles(&mut new_state, event),
+        "apply_constraint_change" => try_apply_constraint_change(&mut new_state, event),
+        "inject_shock" => try_apply_inject_shock(&mut new_state, event, state),
+        other => Err(KernelError::UnknownEventType(other.to_string())),
+    }?;
+
+    record_event_history(&mut new_state, event);
+    Ok((new_state, result))
+}
+
+/// Append `event` to `new_state.event_history` as `to_dict()`, plus a
+/// `resulting_debt` field carrying `new_state.structural_debt` as of
+/// *this* event — so a consumer walking the history (e.g.
+/// `arrow_export::event_history_batch`'s debt-over-time column) sees the
+/// debt at each point in time, not just the final total.
+fn record_event_history(new_state: &mut OrgState, event: &EventEnvelope) {
+    let mut entry = event.to_dict();
+    entry["resulting_debt"] = serde_json::json!(new_state.structural_debt);
+    new_state.event_history.push(entry);
+}
+
+fn missing_field(event_type: &str, field: &str) -> KernelError {
+    KernelError::MissingPayloadField {
+        event_type: event_type.to_string(),
+        field: field.to_string(),
+    }
+}
+
+fn try_checked_add(a: i64, b: i64) -> Result<i64, KernelError> {
+    a.checked_add(b)
+        .ok_or_else(|| KernelError::ConstraintOverflow(format!("{} + {} overflows i64", a, b)))
+}
+
+/// Like `try_checked_add`, but under an `OverflowPolicy` — `Saturating`
+/// never fails, it just clamps instead of erroring.
+fn try_add_with_policy(a: i64, b: i64, policy: OverflowPolicy) -> Result<i64, KernelError> {
+    match policy {
+        OverflowPolicy::Checked => try_checked_add(a, b),
+        OverflowPolicy::Saturating => Ok(policy.add(a, b)),
+    }
+}
+
+fn try_checked_mul(a: i64, b: i64) -> Result<i64, KernelError> {
+    a.checked_mul(b)
+        .ok_or_else(|| KernelError::ConstraintOverflow(format!("{} * {} overflows i64", a, b)))
+}
+
+fn try_apply_initialize_constants(
+    state: &mut OrgState,
+    event: &EventEnvelope,
+) -> Result<TransitionResult, KernelError> {
+    let p = &event.payload;
+    let old = &state.constants;
+    state.constants = DomainConstants {
+        differentiation_threshold: p
+            .get("differentiation_threshold")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.differentiation_threshold),
+        differentiation_min_capacity: p
+            .get("differentiation_min_capacity")
+            .and_then(|v| v.as_i64())
+            .map(Scaled::from_raw)
+            .unwrap_or(old.differentiation_min_capacity),
+        compression_max_combined_responsibilities: p
+            .get("compression_max_combined_responsibilities")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.compression_max_combined_responsibilities),
+        shock_deactivation_threshold: p
+            .get("shock_deactivation_threshold")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.shock_deactivation_threshold),
+        shock_debt_base_multiplier: p
+            .get("shock_debt_base_multiplier")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.shock_debt_base_multiplier),
+        suppressed_differentiation_debt_increment: p
+            .get("suppressed_differentiation_debt_increment")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.suppressed_differentiation_debt_increment),
+        shock_max_propagation_hops: p
+            .get("shock_max_propagation_hops")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.shock_max_propagation_hops),
+        decay_num: p
+            .get("decay_num")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.decay_num),
+        decay_den: p
+            .get("decay_den")
+            .and_then(|v| v.as_i64())
+            .unwrap_or(old.decay_den),
+        overflow_policy: p
+            .get("overflow_policy")
+            .and_then(|v| v.as_str())
+            .map(|s| OverflowPolicy::parse(s, old.overflow_policy))
+            .unwrap_or(old.overflow_policy),
+    };
+
+    Ok(TransitionResult {
+        event_type: "initialize_constants".to_string(),
+        success: true,
+        ..Default::default()
+    })
+}
+
+fn try_apply_add_role(
+    state: &mut OrgState,
+    event: &EventEnvelope,
+) -> Result<TransitionResult, KernelError> {
+    let p = &event.payload;
+    let role_id = p["id"]
+        .as_str()
+        .ok_or_else(|| missing_field("add_role", "id"))?;
+    if role_id.is_empty() || !role_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(missing_field("add_role", "id"));
+    }
+
+    if state.roles.contains_key(role_id) {
+        return Err(KernelError::RoleCollision(role_id.to_string()));
+    }
+
+    let mut responsibilities = json_str_array(p, "responsibilities");
+    responsibilities.sort();
+    let mut required_inputs = json_str_array(p, "required_inputs");
+    required_inputs.sort();
+    let mut produced_outputs = json_str_array(p, "produced_outputs");
+    produced_outputs.sort();
+
+    let scale_stage = p
+        .get("scale_stage")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&state.scale_stage)
+        .to_string();
+
+    let role = Role {
+        id: role_id.to_string(),
+        name: p["name"]
+            .as_str()
+            .ok_or_else(|| missing_field("add_role", "name"))?
+            .to_string(),
+        purpose: p["purpose"]
+            .as_str()
+            .ok_or_else(|| missing_field("add_role", "purpose"))?
+            .to_string(),
+        responsibilities,
+        required_inputs,
+        produced_outputs,
+        scale_stage,
+        active: true,
+    };
+
+    state.roles.insert(role.id.clone(), role);
+
+    Ok(TransitionResult {
+        event_type: "add_role".to_string(),
+        success: true,
+        ..Default::default()
+    })
+}
+
+fn try_apply_remove_role(
+    state: &mut OrgState,
+    event: &EventEnvelope,
+) -> Result<TransitionResult, KernelError> {
+    let role_id = event.payload["role_id"]
+        .as_str()
+        .ok_or_else(|| missing_field("remove_role", "role_id"))?;
+
+    if !state.roles.contains_key(role_id) {
+        return Err(KernelError::MissingRole(role_id.to_string()));
+    }
+
+    state.roles.remove(role_id);
+    state
+        .dependencies
+        .retain(|d| d.from_role_id != role_id && d.to_role_id != role_id);
+
+    Ok(TransitionResult {
+        event_type: "remove_role".to_string(),
+        success: true,
+        ..Default::default()
+    })
+}
+
+fn try_apply_differentiate_role(
+    state: &mut OrgState,
+    event: &EventEnvelope,
+) -> Result<TransitionResult, KernelError> {
+    let p = &event.payload;
+    let role_id = p["role_id"]
+        .as_str()
+        .ok_or_else(|| missing_field("differentiate_role", "role_id"))?;
+
+    let role = state
+        .roles
+        .get(role_id)
+        .ok_or_else(|| KernelError::MissingRole(role_id.to_string()))?
+        .clone();
+
+    let c = state.constants.clone();
+
+    if (role.responsibilities.len() as i64) > c.differentiation_threshold {
+        let capacity = state
+            .constraint_vector
+            .organizational_capacity_index(c.overflow_policy);
+
+        if capacity >= c.differentiation_min_capacity {
+            let new_roles_data = p
+                .get("new_roles")
+                .and_then(|v| v.as_array())
+                .filter(|arr| !arr.is_empty())
+                .ok_or_else(|| missing_field("differentiate_role", "new_roles"))?;
+
+            state.roles.remove(role_id);
+
+            for nr in new_roles_data {
+                let sub_id = nr["id"]
+                    .as_str()
+                    .ok_or_else(|| missing_field("new_role", "id"))?
+                    .to_string();
+                if sub_id.is_empty()
+                    || !sub_id
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+                {
+                    return Err(missing_field("new_role", "id"));
+                }
+
+                let mut responsibilities = json_str_array(nr, "responsibilities");
+                responsibilities.sort();
+
+                let mut required_inputs = if nr.get("required_inputs").is_some()
+                    && nr["required_inputs"].is_array()
+                {
+                    json_str_array(nr, "required_inputs")
+                } else {
+                    role.required_inputs.clone()
+                };
+                required_inputs.sort();
+
+                let mut produced_outputs = json_str_array(nr, "produced_outputs");
+                produced_outputs.sort();
+
+                let sub = Role {
+                    id: sub_id.clone(),
+                    name: nr["name"]
+                        .as_str()
+                        .ok_or_else(|| missing_field("new_role", "name"))?
+                        .to_string(),
+                    purpose: nr
+                        .get("purpose")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or(&role.purpose)
+                        .to_string(),
+                    responsibilities,
+                    required_inputs,
+                    produced_outputs,
+                    scale_stage: role.scale_stage.clone(),
+                    active: true,
+                };
+
+                state.roles.insert(sub.id.clone(), sub);
+            }
+
+            return Ok(TransitionResult {
+                event_type: "differentiate_role".to_string(),
+                success: true,
+                differentiation_executed: true,
+                ..Default::default()
+            });
+        } else {
+            state.structural_debt = try_add_with_policy(
+                state.structural_debt,
+                c.suppressed_differentiation_debt_increment,
+                c.overflow_policy,
+            )?;
+
+            return Ok(TransitionResult {
+                event_type: "differentiate_role".to_string(),
+                success: true,
+                suppressed_differentiation: true,
+                reason: format!(
+                    "capacity={} < differentiation_min_capacity={}",
+                    capacity, c.differentiation_min_capacity
+                ),
+                ..Default::default()
+            });
+        }
+    }
+
+    Ok(TransitionResult {
+        event_type: "differentiate_role".to_string(),
+        success: true,
+        differentiation_skipped: true,
+        reason: format!(
+            "responsibilities={} <= differentiation_threshold={}",
+            role.responsibilities.len(),
+            c.differentiation_threshold
+        ),
+        ..Default::default()
+    })
+}
+
+fn try_apply_compress_roles(
+    state: &mut OrgState,
+    event: &EventEnvelope,
+) -> Result<TransitionResult, KernelError> {
+    let p = &event.payload;
+    let src_id = p["source_role_id"]
+        .as_str()
+        .ok_or_else(|| missing_field("compress_roles", "source_role_id"))?
+        .to_string();
+    let tgt_id = p["target_role_id"]
+        .as_str()
+        .ok_or_else(|| missing_field("compress_roles", "target_role_id"))?
+        .to_string();
+
+    let src = state
+        .roles
+        .get(&src_id)
+        .ok_or_else(|| KernelError::MissingRole(src_id.clone()))?
+        .clone();
+    let tgt = state
+        .roles
+        .get(&tgt_id)
+        .ok_or_else(|| KernelError::MissingRole(tgt_id.clone()))?
+        .clone();
+
+    let c = state.constants.clone();
+
+    let mut combined_set: BTreeSet<String> = BTreeSet::new();
+    for r in &tgt.responsibilities {
+        combined_set.insert(r.clone());
+    }
+    for r in &src.responsibilities {
+        combined_set.insert(r.clone());
+    }
+    let combined: Vec<String> = combined_set.into_iter().collect();
+
+    if (combined.len() as i64) > c.compression_max_combined_responsibilities {
+        return Err(KernelError::CompressionLimitExceeded {
+            combined: combined.len() as i64,
+            limit: c.compression_max_combined_responsibilities,
+        });
+    }
+
+    let target = state.roles.get_mut(&tgt_id).unwrap();
+    target.name = p
+        .get("compressed_name")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&tgt.name)
+        .to_string();
+    target.purpose = p
+        .get("compressed_purpose")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&tgt.purpose)
+        .to_string();
+    target.responsibilities = combined;
+
+    let mut input_set: BTreeSet<String> = BTreeSet::new();
+    for i in &tgt.required_inputs {
+        input_set.insert(i.clone());
+    }
+    for i in &src.required_inputs {
+        input_set.insert(i.clone());
+    }
+    target.required_inputs = input_set.into_iter().collect();
+
+    let mut output_set: BTreeSet<String> = BTreeSet::new();
+    for o in &tgt.produced_outputs {
+        output_set.insert(o.clone());
+    }
+    for o in &src.produced_outputs {
+        output_set.insert(o.clone());
+    }
+    target.produced_outputs = output_set.into_iter().collect();
+
+    state.roles.remove(&src_id);
+
+    for dep in &mut state.dependencies {
+        if dep.from_role_id == src_id {
+            dep.from_role_id = tgt_id.clone();
+        }
+        if dep.to_role_id == src_id {
+            dep.to_role_id = tgt_id.clone();
+        }
+    }
+
+    state.dependencies.retain(|d| d.from_role_id != d.to_role_id);
+
+    Ok(TransitionResult {
+        event_type: "compress_roles".to_string(),
+        success: true,
+        compression_executed: true,
+        ..Default::default()
+    })
+}
+
+fn try_apply_constraint_change(
+    state: &mut OrgState,
+    event: &EventEnvelope,
+) -> Result<TransitionResult, KernelError> {
+    let p = &event.payload;
+    let cv = &mut state.constraint_vector;
+
+    cv.capital = Scaled::from_raw(try_checked_add(cv.capital.raw(), json_i64(p, "capital_delta"))?);
+    cv.talent = Scaled::from_raw(try_checked_add(cv.talent.raw(), json_i64(p, "talent_delta"))?);
+    cv.time = Scaled::from_raw(try_checked_add(cv.time.raw(), json_i64(p, "time_delta"))?);
+    cv.political_cost = Scaled::from_raw(try_checked_add(
+        cv.political_cost.raw(),
+        json_i64(p, "political_cost_delta"),
+    )?);
+
+    if cv.capital.is_negative()
+        || cv.talent.is_negative()
+        || cv.time.is_negative()
+        || cv.political_cost.is_negative()
+    {
+        return Err(KernelError::NegativeConstraint(format!(
+            "capital={} talent={} time={} political_cost={}",
+            cv.capital, cv.talent, cv.time, cv.political_cost
+        )));
+    }
+
+    Ok(TransitionResult {
+        event_type: "apply_constraint_change".to_string(),
+        success: true,
+        ..Default::default()
+    })
+}
+
+fn try_apply_inject_shock(
+    state: &mut OrgState,
+    event: &EventEnvelope,
+    original_state: &OrgState,
+) -> Result<TransitionResult, KernelError> {
+    let p = &event.payload;
+    let target_id = p["target_role_id"]
+        .as_str()
+        .ok_or_else(|| missing_field("inject_shock", "target_role_id"))?
+        .to_string();
+    let magnitude = p["magnitude"]
+        .as_i64()
+        .ok_or_else(|| missing_field("inject_shock", "magnitude"))?;
+
+    if !state.roles.contains_key(&target_id) {
+        return Err(KernelError::MissingRole(target_id));
+    }
+
+    let c = state.constants.clone();
+
+    let target_density = compute_role_structural_density(&target_id, original_state);
+
+    let mut primary_debt =
+        try_checked_mul(magnitude, try_checked_add(c.shock_debt_base_multiplier, target_density)?)?;
+    primary_debt = primary_debt.max(1);
+    state.structural_debt = try_add_with_policy(state.structural_debt, primary_debt, c.overflow_policy)?;
+
+    let mut deactivated = false;
+    if magnitude > c.shock_deactivation_threshold {
+        state.roles.get_mut(&target_id).unwrap().active = false;
+        deactivated = true;
+    }
+
+    let adjacency = undirected_adjacency(original_state);
+    let (secondary_debt, reached_role_count, hop_debt_breakdown) = try_propagate_shock_bfs(
+        &target_id,
+        magnitude,
+        c.shock_max_propagation_hops,
+        c.decay_num,
+        c.decay_den,
+        &adjacency,
+        original_state,
+    )?;
+
+    state.structural_debt = try_add_with_policy(state.structural_debt, secondary_debt, c.overflow_policy)?;
+
+    Ok(TransitionResult {
+        event_type: "inject_shock".to_string(),
+        success: true,
+        deactivated,
+        shock_target: target_id,
+        magnitude,
+        primary_debt,
+        secondary_debt,
+        target_density,
+        reached_role_count,
+        hop_debt_breakdown,
+        ..Default::default()
+    })
+}
+
+/// Non-panicking companion to `propagate_shock_bfs`.
+fn try_propagate_shock_bfs(
+    target_id: &str,
+    magnitude: i64,
+    max_hops: i64,
+    decay_num: i64,
+    decay_den: i64,
+    adjacency: &std::collections::BTreeMap<String, BTreeSet<String>>,
+    original_state: &OrgState,
+) -> Result<(i64, i64, Vec<i64>), KernelError> {
+    use std::collections::VecDeque;
+
+    let mut visited: BTreeSet<String> = BTreeSet::new();
+    visited.insert(target_id.to_string());
+
+    let mut queue: VecDeque<(String, i64)> = VecDeque::new();
+    queue.push_back((target_id.to_string(), 0));
+
+    let mut secondary_debt: i64 = 0;
+    let mut reached_role_count: i64 = 0;
+    let mut hop_debt_breakdown: Vec<i64> = Vec::new();
+
+    while let Some((node, depth)) = queue.pop_front() {
+        if depth >= max_hops {
+            continue;
+        }
+        let neighbors = match adjacency.get(&node) {
+            Some(n) => n.clone(),
+            None => continue,
+        };
+        for nbr in neighbors {
+            if visited.contains(&nbr) {
+                continue;
+            }
+            visited.insert(nbr.clone());
+            let hop = depth + 1;
+
+            if original_state.roles.contains_key(&nbr) {
+                let density = compute_role_structural_density(&nbr, original_state);
+                let mut numerator = try_checked_mul(magnitude, density)?;
+                for _ in 0..hop {
+                    numerator = try_checked_mul(numerator, decay_num)?;
+                }
+                let mut denominator: i64 = 1;
+                for _ in 0..hop {
+                    denominator = try_checked_mul(denominator, decay_den)?;
+                }
+                let inc_raw = numerator / denominator.max(1);
+
+                if inc_raw < 1 {
+                    continue;
+                }
+
+                secondary_debt = try_checked_add(secondary_debt, inc_raw)?;
+                reached_role_count = try_checked_add(reached_role_count, 1)?;
+                let idx = (hop - 1) as usize;
+                while hop_debt_breakdown.len() <= idx {
+                    hop_debt_breakdown.push(0);
+                }
+                hop_debt_breakdown[idx] = try_checked_add(hop_debt_breakdown[idx], inc_raw)?;
+            }
+
+            queue.push_back((nbr, hop));
+        }
+    }
+
+    Ok((secondary_debt, reached_role_count, hop_debt_breakdown))
+}
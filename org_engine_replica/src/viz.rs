@@ -0,0 +1,73 @@
+/// OrgEngine v1.1 — Graphviz DOT Export
+///
+/// Renders an `OrgState`'s `roles` and `dependencies` as a Graphviz
+/// `digraph`, so maintainers can visually diff structural evolution
+/// (differentiation, compression, shock fallout) across sequences
+/// instead of reading JSON diffs.
+///
+/// Pure and read-only: it only reads `OrgState`, never mutates it and
+/// never re-runs kernel transition logic.
+use crate::domain::OrgState;
+
+/// Escape a string for use inside a DOT quoted identifier or label.
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Node styling attributes for one role — dimmed and dashed when
+/// `role.active` is false, so deactivated roles read as "faded" rather
+/// than simply absent.
+fn node_style(active: bool) -> &'static str {
+    if active {
+        "style=filled, fillcolor=\"#ffffff\", color=\"#222222\", fontcolor=\"#222222\""
+    } else {
+        "style=\"filled,dashed\", fillcolor=\"#eeeeee\", color=\"#999999\", fontcolor=\"#999999\""
+    }
+}
+
+/// Render an `OrgState` as a Graphviz DOT `digraph`.
+///
+/// - One node per role, labeled with `id` and `name`; inactive roles are
+///   dimmed and dashed.
+/// - One directed edge per `DependencyEdge` (`from_role_id -> to_role_id`),
+///   labeled with `dependency_type` and drawn bold when `critical`.
+/// - The graph label annotates the current `structural_debt`.
+///
+/// Roles and edges are emitted in `BTreeMap`/declaration order, so the
+/// rendered DOT is deterministic across runs of the same state.
+pub fn to_dot(state: &OrgState) -> String {
+    let mut out = String::new();
+    out.push_str("digraph org_state {\n");
+    out.push_str(&format!(
+        "  label=\"structural_debt={}\";\n",
+        state.structural_debt
+    ));
+    out.push_str("  labelloc=\"t\";\n");
+    out.push_str("  node [shape=box, fontname=\"Helvetica\"];\n\n");
+
+    for role in state.roles.values() {
+        out.push_str(&format!(
+            "  \"{}\" [label=\"{}\\n{}\", {}];\n",
+            escape_dot(&role.id),
+            escape_dot(&role.id),
+            escape_dot(&role.name),
+            node_style(role.active)
+        ));
+    }
+
+    out.push('\n');
+
+    for edge in &state.dependencies {
+        let style = if edge.critical { ", penwidth=2" } else { "" };
+        out.push_str(&format!(
+            "  \"{}\" -> \"{}\" [label=\"{}\"{}];\n",
+            escape_dot(&edge.from_role_id),
+            escape_dot(&edge.to_role_id),
+            escape_dot(&edge.dependency_type),
+            style
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "proptest")]
+//! OrgEngine v1.1 — Generative replay/invariant properties
+//!
+//! Complements `golden_test.rs` (one frozen event stream, a fixed
+//! expected hash) with randomly generated well-formed streams, checking
+//! properties that must hold for *any* such stream rather than one
+//! pinned fixture. Generators live in `proptest_support` (feature-gated
+//! the same way) — see that module's doc comment for why this is a
+//! `proptest::Strategy` subsystem rather than the `arbitrary`-crate one
+//! `fuzz/` already uses.
+
+use proptest::prelude::*;
+
+use org_engine_replica::engine::OrgEngine;
+use org_engine_replica::events::{EventEnvelope, SCHEMA_VERSION};
+use org_engine_replica::hashing::canonical_hash;
+use org_engine_replica::proptest_support::event_stream_strategy;
+use parity_scale_codec::{Decode, Encode};
+
+fn replay(events: &[EventEnvelope]) -> OrgEngine {
+    let mut engine = OrgEngine::new();
+    engine.initialize_state();
+    for event in events {
+        engine.apply_event(event);
+    }
+    engine
+}
+
+proptest! {
+    /// (1) Replay determinism: a SCALE round trip of `OrgState` through
+    /// a snapshot (encode, decode, resume from the decoded state) must
+    /// reproduce the exact same canonical hash as the original engine.
+    #[test]
+    fn replay_survives_snapshot_round_trip(events in event_stream_strategy()) {
+        let engine = replay(&events);
+        let before = canonical_hash(engine.state());
+
+        let bytes = engine.state().encode();
+        let restored = org_engine_replica::domain::OrgState::decode(&mut &bytes[..])
+            .expect("snapshot round trip must decode cleanly");
+        let resumed = OrgEngine::resume_from_state(restored, engine.last_sequence());
+        let after = canonical_hash(resumed.state());
+
+        prop_assert_eq!(before, after);
+    }
+
+    /// (2) `structural_debt` never decreases as a well-formed stream of
+    /// shock/suppression-capable events is applied, whatever the mix of
+    /// `add_role`/`inject_shock` events the generator produced.
+    #[test]
+    fn structural_debt_is_monotonic_non_decreasing(events in event_stream_strategy()) {
+        let mut engine = OrgEngine::new();
+        engine.initialize_state();
+        let mut previous_debt = engine.state().structural_debt;
+        for event in &events {
+            engine.apply_event(event);
+            let debt = engine.state().structural_debt;
+            prop_assert!(debt >= previous_debt);
+            previous_debt = debt;
+        }
+    }
+
+    /// (3) The aggregate capacity index is a mean of its four inputs, so
+    /// it can never exceed their max, for any non-negative
+    /// `ConstraintVector` and either `OverflowPolicy`.
+    #[test]
+    fn capacity_index_never_exceeds_max_input(
+        cv in org_engine_replica::proptest_support::constraint_vector_strategy(),
+    ) {
+        let max_input = cv.capital.raw()
+            .max(cv.talent.raw())
+            .max(cv.time.raw())
+            .max(cv.political_cost.raw());
+        use org_engine_replica::arithmetic::OverflowPolicy;
+        for policy in [OverflowPolicy::Checked, OverflowPolicy::Saturating] {
+            let index = cv.organizational_capacity_index(policy).raw();
+            prop_assert!(index <= max_input);
+        }
+    }
+
+    /// (4) Rejecting an envelope whose `schema_version` doesn't match
+    /// `SCHEMA_VERSION` must leave engine state untouched — checked by
+    /// comparing the canonical hash before and after the rejected call.
+    #[test]
+    fn rejected_schema_mismatch_leaves_state_untouched(events in event_stream_strategy()) {
+        let mut engine = replay(&events);
+        let before = canonical_hash(engine.state());
+        let last_sequence = engine.last_sequence();
+
+        let bad_envelope = EventEnvelope {
+            event_type: "add_role".to_string(),
+            sequence: last_sequence + 1,
+            timestamp: String::new(),
+            logical_time: 0,
+            payload: serde_json::json!({"id": "zz", "name": "n", "purpose": "p"}),
+            schema_version: SCHEMA_VERSION + 1,
+        };
+
+        let result = engine.try_apply_event(&bad_envelope);
+        prop_assert!(result.is_err());
+        prop_assert_eq!(before, canonical_hash(engine.state()));
+    }
+}
+
+/// `try_apply_event` must not panic on a schema- and handler-valid event
+/// that merely violates a domain invariant (here: `add_role` with zero
+/// responsibilities, which trips `NoEmptyResponsibilities`) — it should
+/// surface `KernelError::InvariantViolation` and leave state untouched,
+/// the same as the schema/sequence/constants-first error paths.
+#[test]
+fn invariant_violation_is_reported_not_panicked() {
+    let mut engine = OrgEngine::new();
+    engine.initialize_state();
+    engine
+        .try_apply_event(&EventEnvelope {
+            event_type: "initialize_constants".to_string(),
+            sequence: 1,
+            timestamp: String::new(),
+            logical_time: 0,
+            payload: serde_json::json!({}),
+            schema_version: SCHEMA_VERSION,
+        })
+        .expect("initialize_constants must succeed");
+    let before = canonical_hash(engine.state());
+
+    let bad_role = EventEnvelope {
+        event_type: "add_role".to_string(),
+        sequence: 2,
+        timestamp: String::new(),
+        logical_time: 0,
+        payload: serde_json::json!({"id": "r1", "name": "n", "purpose": "p"}),
+        schema_version: SCHEMA_VERSION,
+    };
+
+    let result = engine.try_apply_event(&bad_role);
+    assert!(matches!(
+        result,
+        Err(org_engine_replica::error::KernelError::InvariantViolation(_))
+    ));
+    assert_eq!(before, canonical_hash(engine.state()));
+}
@@ -0,0 +1,64 @@
+/// Golden DOT-rendering test — replays the frozen event stream and
+/// asserts the Graphviz DOT rendering matches a stored fixture.
+///
+/// Mirrors `golden_replay_hash_matches` in golden_test.rs: if this
+/// fails, either the kernel state shape changed or `viz::to_dot`'s
+/// rendering changed — both are worth a deliberate look, not a
+/// silent fixture update.
+use std::fs;
+
+use org_engine_replica::engine::OrgEngine;
+use org_engine_replica::events::EventEnvelope;
+use org_engine_replica::viz::to_dot;
+
+fn load_events(path: &str) -> Vec<EventEnvelope> {
+    let data = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e));
+    let arr: Vec<serde_json::Value> =
+        serde_json::from_str(&data).expect("Failed to parse events JSON");
+    arr.iter().map(|v| EventEnvelope::from_value(v)).collect()
+}
+
+#[test]
+fn golden_dot_rendering_matches() {
+    let events = load_events("tests/golden/events.json");
+    let mut engine = OrgEngine::new();
+    engine.initialize_state();
+    for evt in &events {
+        engine.apply_event(evt);
+    }
+    let dot = to_dot(engine.state());
+
+    let expected = fs::read_to_string("tests/golden/expected.dot")
+        .unwrap_or_else(|e| panic!("Failed to read tests/golden/expected.dot: {}", e));
+
+    assert_eq!(
+        dot.trim_end(),
+        expected.trim_end(),
+        "GOLDEN DOT TEST FAILED: rendering of the frozen event stream changed."
+    );
+}
+
+#[test]
+fn dot_rendering_is_deterministic() {
+    let events = load_events("tests/golden/events.json");
+
+    let mut engine1 = OrgEngine::new();
+    engine1.initialize_state();
+    for evt in &events {
+        engine1.apply_event(evt);
+    }
+    let dot1 = to_dot(engine1.state());
+
+    let mut engine2 = OrgEngine::new();
+    engine2.initialize_state();
+    for evt in &events {
+        engine2.apply_event(evt);
+    }
+    let dot2 = to_dot(engine2.state());
+
+    assert_eq!(
+        dot1, dot2,
+        "DETERMINISM FAILURE: two DOT renderings of the same events differ."
+    );
+}
@@ -0,0 +1,102 @@
+//! Signed event-log hash chain.
+//!
+//! `EventStore`'s `.chain` sidecar (see `event_store.rs`) already lets
+//! `verify_chain`/`Session::verify_chain` detect an in-place edit or
+//! reorder of the log. This module adds the same TUF-style attestation
+//! `snapshot_sig.rs` gives snapshots, applied to chain heads instead: a
+//! hex ed25519 signature over the head, verified against a
+//! content-derived `PublicKey` rather than a caller-chosen label. That
+//! catches what recomputing the chain alone cannot — a wholesale log
+//! swap for a different, internally-consistent one.
+
+use serde::{Deserialize, Serialize};
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier};
+
+use crate::compaction::{hex_decode, to_hex};
+use crate::snapshot_sig::{key_id, PublicKey};
+
+/// One signature over a chain head, in the same `{key_id, sig}` shape as
+/// `snapshot_sig::SnapshotSignature`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChainHeadSignature {
+    /// Content-derived identity of the signing key (see `snapshot_sig::key_id`).
+    pub key_id: String,
+    /// Lowercase-hex ed25519 signature over the 32-byte chain head.
+    pub sig: String,
+}
+
+/// Sign a chain head with `signing_key`.
+pub fn sign_chain_head(head: &[u8; 32], signing_key: &SigningKey) -> ChainHeadSignature {
+    let sig: Signature = signing_key.sign(head);
+    let pk = PublicKey::from_signing_key(signing_key);
+    ChainHeadSignature {
+        key_id: key_id(&pk),
+        sig: to_hex(&sig.to_bytes()),
+    }
+}
+
+/// Check a `ChainHeadSignature` against a known, trusted `PublicKey`.
+/// `false` for a malformed hex `sig`, a `key_id` that doesn't match
+/// `trusted`, or a signature that fails to verify.
+pub fn verify_chain_head_signature(
+    head: &[u8; 32],
+    signature: &ChainHeadSignature,
+    trusted: &PublicKey,
+) -> bool {
+    if key_id(trusted) != signature.key_id {
+        return false;
+    }
+    let Some(bytes) = hex_decode(&signature.sig) else {
+        return false;
+    };
+    if bytes.len() != 64 {
+        return false;
+    }
+    let mut sig_array = [0u8; 64];
+    sig_array.copy_from_slice(&bytes);
+    let sig = Signature::from_bytes(&sig_array);
+    trusted.verifying_key.verify(head, &sig).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    #[test]
+    fn valid_signature_verifies() {
+        let key = signing_key(1);
+        let head = [7u8; 32];
+        let signature = sign_chain_head(&head, &key);
+        let trusted = PublicKey::from_signing_key(&key);
+
+        assert!(verify_chain_head_signature(&head, &signature, &trusted));
+    }
+
+    #[test]
+    fn tampered_head_is_rejected() {
+        let key = signing_key(1);
+        let head = [7u8; 32];
+        let signature = sign_chain_head(&head, &key);
+        let trusted = PublicKey::from_signing_key(&key);
+
+        let tampered_head = [8u8; 32];
+        assert!(!verify_chain_head_signature(&tampered_head, &signature, &trusted));
+    }
+
+    #[test]
+    fn signature_from_untrusted_key_is_rejected() {
+        let key = signing_key(1);
+        let other = signing_key(2);
+        let head = [7u8; 32];
+        let signature = sign_chain_head(&head, &key);
+        let untrusted = PublicKey::from_signing_key(&other);
+
+        assert!(!verify_chain_head_signature(&head, &signature, &untrusted));
+    }
+}
@@ -0,0 +1,93 @@
+//! Snapshot-based log compaction.
+//!
+//! Mirrors the journaled/pruned state DB approach of a journal-backed
+//! store: once a verified snapshot exists at sequence `S`, every event
+//! with `sequence <= S` is redundant — replay can resume from the
+//! snapshot instead of from sequence 1. `EventStore::compact_to` prunes
+//! those frames from the log and records a `CompactionManifest`
+//! sidecar (`<path>.compaction`) carrying the hash chain head at the
+//! cut point, so a later `verify_chain` resumes hashing from there
+//! instead of assuming the log still starts at genesis.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Sidecar path for a compacted log at `path`: `<path>.compaction`.
+pub fn compaction_manifest_path(path: &Path) -> PathBuf {
+    let mut os = path.as_os_str().to_os_string();
+    os.push(".compaction");
+    PathBuf::from(os)
+}
+
+/// Recorded after `EventStore::compact_to` prunes the log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompactionManifest {
+    /// Every frame with `sequence <= snapshot_seq` was pruned.
+    pub snapshot_seq: u64,
+    /// The snapshot's own recorded hash, kept for audit — this is the
+    /// state replay resumes from in place of the pruned frames.
+    pub snapshot_hash: String,
+    /// Hex-encoded hash chain head immediately after `snapshot_seq` —
+    /// what `chain_step` would have produced replaying from genesis
+    /// through the pruned frames. Any frame still in the log continues
+    /// the chain from here instead of from genesis.
+    pub chain_head: String,
+}
+
+/// Load the compaction manifest for a log at `path`, if it was ever
+/// compacted.
+pub fn load_compaction_manifest(path: &Path) -> io::Result<Option<CompactionManifest>> {
+    let manifest_path = compaction_manifest_path(path);
+    if !manifest_path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&manifest_path)?;
+    let manifest = serde_json::from_str(&content).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("bad compaction manifest: {}", e),
+        )
+    })?;
+    Ok(Some(manifest))
+}
+
+/// Write the compaction manifest atomically: temp file in the same
+/// directory, fsync, then rename over the target — a concurrent reader
+/// never observes a partially-written manifest.
+pub fn write_compaction_manifest_atomic(
+    path: &Path,
+    manifest: &CompactionManifest,
+) -> io::Result<()> {
+    let manifest_path = compaction_manifest_path(path);
+    let tmp_path = {
+        let mut os = manifest_path.as_os_str().to_os_string();
+        os.push(".tmp");
+        PathBuf::from(os)
+    };
+    let content =
+        serde_json::to_string(manifest).expect("compaction manifest serialization failed");
+
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&tmp_path, &manifest_path)?;
+    Ok(())
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
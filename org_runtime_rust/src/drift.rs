@@ -13,11 +13,18 @@ use crate::replay;
 
 /// Verify determinism by replaying the same events twice and
 /// asserting identical hashes. Panics on failure.
+///
+/// Under the `telemetry` feature, a mismatch is recorded against the
+/// `verify_determinism` counter before the panic (see
+/// `telemetry::record_determinism_mismatch`) — the counter is what
+/// survives for an operator to see even though the process then aborts.
 pub fn verify_determinism(events: &[EventEnvelope]) {
     let (_, hash1) = replay::rebuild_state(events);
     let (_, hash2) = replay::rebuild_state(events);
 
     if hash1 != hash2 {
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::record_determinism_mismatch();
         panic!(
             "DETERMINISM FAILURE: two replays produced different hashes.\n\
              Run 1: {}\n\
@@ -1,55 +1,204 @@
 //! Append-only event store — binary protobuf log.
 //!
-//! Storage format: length-prefixed protobuf frames.
-//!   [4-byte LE length][protobuf bytes][4-byte LE length][protobuf bytes]...
+//! Storage format: length- and checksum-prefixed protobuf frames.
+//!   [4-byte LE length][4-byte LE CRC32 of payload][protobuf bytes]...
 //!
 //! Rules:
 //!   - Strict append only — no mutation, no deletion, no reordering
 //!   - fsync after every write
 //!   - Sequence strictly increasing (validated on append)
 //!   - Events with schema_version != 1 are rejected by the kernel
+//!
+//! The CRC32 catches mid-frame bit flips that would otherwise decode
+//! into a silently wrong event — `open`/`load_all_events`/`verify_chain`
+//! treat a checksum mismatch exactly like a decode error: the whole
+//! read fails. For a log whose tail was torn by a crash mid-write,
+//! `load_valid_prefix` instead scans and stops cleanly at the first
+//! bad frame, returning everything before it plus the byte offset to
+//! recover to; `repair_truncate` performs that recovery by rewriting
+//! the file (and its chain sidecar) to that offset. This gives a
+//! WAL-style recoverable log instead of all-or-nothing loading.
+//!
+//! The log is also a hash chain: a `.chain` sidecar file stores one
+//! 32-byte digest per frame, `h_i = SHA256(h_{i-1} || len_le || frame)`
+//! starting from `h_0 = SHA256("")`. This localizes tamper detection:
+//! a raw bit-flip already fails the CRC check above (the read fails,
+//! naming the byte offset), while a frame rewritten with a valid CRC is
+//! caught by `verify_chain` at the exact sequence where it happened.
 
 use std::fs::{File, OpenOptions};
 use std::io::{self, BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
 use prost::Message;
+use sha2::{Digest, Sha256};
 
+use crate::compaction::{
+    self, hex_decode, to_hex, CompactionManifest,
+};
 use crate::proto_types::ProtoEventEnvelope;
+use crate::snapshot::{verify_snapshot_hash, HashAlgorithm, Snapshot};
+use crate::storage_backend::StorageBackend;
+
+/// One decoded frame plus the raw bytes it was built from, needed to
+/// recompute the hash chain without re-encoding (re-encoding a decoded
+/// protobuf message is not guaranteed byte-identical to its source).
+struct RawFrame {
+    event: ProtoEventEnvelope,
+    len_bytes: [u8; 4],
+    frame_bytes: Vec<u8>,
+}
+
+/// Where a `load_valid_prefix` scan stopped, and why.
+#[derive(Debug)]
+pub struct CorruptionInfo {
+    /// Byte offset into the log file where the bad frame begins — also
+    /// the offset `repair_truncate` rewrites the file to.
+    pub offset: u64,
+    pub kind: CorruptionKind,
+}
+
+/// The specific way a frame failed to read cleanly.
+#[derive(Debug)]
+pub enum CorruptionKind {
+    /// The 4-byte length prefix was zero or exceeded the frame size cap.
+    InvalidLength(usize),
+    /// Fewer payload bytes were available than the length prefix promised.
+    TruncatedFrame,
+    /// The stored CRC32 did not match the payload's recomputed CRC32.
+    ChecksumMismatch,
+    /// The payload passed its checksum but failed to decode as protobuf.
+    DecodeError(String),
+}
+
+impl std::fmt::Display for CorruptionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorruptionKind::InvalidLength(len) => write!(f, "invalid frame length {}", len),
+            CorruptionKind::TruncatedFrame => write!(f, "truncated frame"),
+            CorruptionKind::ChecksumMismatch => write!(f, "checksum mismatch"),
+            CorruptionKind::DecodeError(e) => write!(f, "protobuf decode error: {}", e),
+        }
+    }
+}
 
-/// Append-only event log backed by a binary file.
+impl std::fmt::Display for CorruptionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at offset {}", self.kind, self.offset)
+    }
+}
+
+/// CRC-32 (IEEE 802.3, the zlib/gzip/png variant) over `data`.
+/// Hand-rolled bitwise form — no lookup table, no external crate.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// Append-only event log backed by a binary file, chained by hash.
 pub struct EventStore {
     path: PathBuf,
+    chain_path: PathBuf,
     last_sequence: u64,
+    head: [u8; 32],
+}
+
+/// A `verify_chain` divergence or I/O failure.
+#[derive(Debug)]
+pub enum ChainError {
+    /// The recomputed digest at this sequence does not match the
+    /// digest stored in the `.chain` sidecar.
+    Diverged { sequence: u64 },
+    Io(io::Error),
+}
+
+impl std::fmt::Display for ChainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChainError::Diverged { sequence } => {
+                write!(f, "hash chain diverges at sequence {}", sequence)
+            }
+            ChainError::Io(e) => write!(f, "io error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ChainError {}
+
+impl From<io::Error> for ChainError {
+    fn from(e: io::Error) -> Self {
+        ChainError::Io(e)
+    }
+}
+
+/// `h_0` — the hash chain's genesis digest, `SHA256("")`.
+fn genesis_hash() -> [u8; 32] {
+    Sha256::digest(b"").into()
+}
+
+/// `h_i = SHA256(h_{i-1} || len_le || frame_bytes)`.
+fn chain_step(prev: &[u8; 32], len_bytes: &[u8; 4], frame_bytes: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(prev);
+    hasher.update(len_bytes);
+    hasher.update(frame_bytes);
+    hasher.finalize().into()
+}
+
+/// Sidecar path for a log at `path`: `<path>.chain`.
+fn chain_path_for(path: &Path) -> PathBuf {
+    let mut os = path.as_os_str().to_os_string();
+    os.push(".chain");
+    PathBuf::from(os)
 }
 
 impl EventStore {
     /// Open or create an event log at the given path.
-    /// Reads existing events to determine the last sequence number.
+    /// Reads existing events to determine the last sequence number and
+    /// recomputes the hash chain head. If the log was ever compacted
+    /// with `compact_to`, both the chain's starting hash and the floor
+    /// for `last_sequence` are taken from the recorded
+    /// `CompactionManifest` rather than assumed to be genesis/zero.
     pub fn open(path: &Path) -> io::Result<Self> {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
 
-        // Read existing events to determine last sequence
-        let last_sequence = if path.exists() {
-            let events = Self::read_all_from_file(path)?;
-            events.last().map(|e| e.sequence).unwrap_or(0)
+        let chain_path = chain_path_for(path);
+        let manifest = compaction::load_compaction_manifest(path)?;
+        let base_head = Self::base_head_from_manifest(manifest.as_ref())?;
+        let floor_sequence = manifest.as_ref().map(|m| m.snapshot_seq).unwrap_or(0);
+
+        let (last_sequence, head) = if path.exists() {
+            let frames = Self::read_all_raw_frames(path)?;
+            let last_sequence = frames.last().map(|f| f.event.sequence).unwrap_or(floor_sequence);
+            let head = Self::compute_chain_head(base_head, &frames);
+            (last_sequence, head)
         } else {
-            0
+            (floor_sequence, base_head)
         };
 
         Ok(Self {
             path: path.to_path_buf(),
+            chain_path,
             last_sequence,
+            head,
         })
     }
 
     /// Append a single event to the log.
     ///
     /// Validates strict sequence ordering.
-    /// Writes length-prefixed protobuf and fsyncs.
+    /// Writes length-prefixed protobuf and fsyncs, then extends the
+    /// hash chain sidecar with the new head digest and fsyncs that too.
     pub fn append_event(&mut self, event: &ProtoEventEnvelope) -> io::Result<()> {
         let expected = self.last_sequence + 1;
         if event.sequence != expected {
@@ -62,22 +211,35 @@ impl EventStore {
             ));
         }
 
-        let mut file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&self.path)?;
-
         let buf = event.encode_to_vec();
         let len = buf.len() as u32;
+        let len_bytes = len.to_le_bytes();
+        let crc_bytes = crc32(&buf).to_le_bytes();
 
         {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
             let mut writer = BufWriter::new(&mut file);
-            writer.write_all(&len.to_le_bytes())?;
+            writer.write_all(&len_bytes)?;
+            writer.write_all(&crc_bytes)?;
             writer.write_all(&buf)?;
             writer.flush()?;
+            file.sync_all()?;
         }
-        file.sync_all()?;
 
+        let new_head = chain_step(&self.head, &len_bytes, &buf);
+        {
+            let mut chain_file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.chain_path)?;
+            chain_file.write_all(&new_head)?;
+            chain_file.sync_all()?;
+        }
+
+        self.head = new_head;
         self.last_sequence = event.sequence;
         Ok(())
     }
@@ -87,7 +249,10 @@ impl EventStore {
         if !self.path.exists() {
             return Ok(Vec::new());
         }
-        Self::read_all_from_file(&self.path)
+        Ok(Self::read_all_raw_frames(&self.path)?
+            .into_iter()
+            .map(|f| f.event)
+            .collect())
     }
 
     /// Get the last sequence number in the log.
@@ -95,46 +260,393 @@ impl EventStore {
         self.last_sequence
     }
 
-    /// Read all events from a file, validating frame integrity.
-    fn read_all_from_file(path: &Path) -> io::Result<Vec<ProtoEventEnvelope>> {
+    /// The current hash chain head — lets a caller cheaply attest "my
+    /// log is exactly these N events" without re-reading the whole file.
+    pub fn chain_head(&self) -> [u8; 32] {
+        self.head
+    }
+
+    /// Recompute the hash chain from the main log and compare it
+    /// against the `.chain` sidecar frame by frame, returning the first
+    /// sequence at which they diverge. A frame that fails its CRC or
+    /// decode makes this return `ChainError::Io` instead, before any
+    /// digest is compared.
+    pub fn verify_chain(&self) -> Result<(), ChainError> {
+        let frames = Self::read_all_raw_frames(&self.path)?;
+        let stored = Self::read_chain_sidecar(&self.chain_path)?;
+
+        if stored.len() != frames.len() {
+            let sequence = frames
+                .get(stored.len())
+                .map(|f| f.event.sequence)
+                .unwrap_or_else(|| frames.len() as u64 + 1);
+            return Err(ChainError::Diverged { sequence });
+        }
+
+        let manifest = compaction::load_compaction_manifest(&self.path)?;
+        let mut h = Self::base_head_from_manifest(manifest.as_ref())?;
+        for (frame, stored_hash) in frames.iter().zip(stored.iter()) {
+            h = chain_step(&h, &frame.len_bytes, &frame.frame_bytes);
+            if &h != stored_hash {
+                return Err(ChainError::Diverged {
+                    sequence: frame.event.sequence,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Every intermediate chain head, one per frame in ascending
+    /// sequence order — `verify_chain` only checks the final sidecar
+    /// entries match, this additionally hands back the full sequence for
+    /// callers (e.g. a signature verifier) that need the head as of each
+    /// individual event, not just the latest one.
+    pub fn chain_head_sequence(&self) -> io::Result<Vec<[u8; 32]>> {
+        let frames = Self::read_all_raw_frames(&self.path)?;
+        let manifest = compaction::load_compaction_manifest(&self.path)?;
+        let mut h = Self::base_head_from_manifest(manifest.as_ref())?;
+        let mut heads = Vec::with_capacity(frames.len());
+        for frame in &frames {
+            h = chain_step(&h, &frame.len_bytes, &frame.frame_bytes);
+            heads.push(h);
+        }
+        Ok(heads)
+    }
+
+    fn compute_chain_head(start: [u8; 32], frames: &[RawFrame]) -> [u8; 32] {
+        let mut h = start;
+        for frame in frames {
+            h = chain_step(&h, &frame.len_bytes, &frame.frame_bytes);
+        }
+        h
+    }
+
+    /// The hash chain's starting point for this log: genesis, unless a
+    /// `CompactionManifest` recorded the head left behind by a prior
+    /// `compact_to`, in which case the chain picks up from there.
+    fn base_head_from_manifest(manifest: Option<&CompactionManifest>) -> io::Result<[u8; 32]> {
+        let Some(manifest) = manifest else {
+            return Ok(genesis_hash());
+        };
+        let bytes = hex_decode(&manifest.chain_head).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "compaction manifest chain_head is not valid hex",
+            )
+        })?;
+        if bytes.len() != 32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "compaction manifest chain_head is not 32 bytes",
+            ));
+        }
+        let mut head = [0u8; 32];
+        head.copy_from_slice(&bytes);
+        Ok(head)
+    }
+
+    /// Prune every frame with `sequence <= snapshot.sequence` from the
+    /// log, refusing to do so unless `snapshot` passes its own hash
+    /// check and covers a sequence the log has actually reached.
+    /// Rewrites the log and its `.chain` sidecar to hold only the
+    /// retained frames, and records a `CompactionManifest` carrying the
+    /// chain head at the cut point so `verify_chain`/`open` can resume
+    /// hashing from there instead of from genesis.
+    pub fn compact_to(&mut self, snapshot: &Snapshot) -> io::Result<()> {
+        if !verify_snapshot_hash(snapshot) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "refusing to compact: snapshot failed its own hash check",
+            ));
+        }
+        if snapshot.sequence > self.last_sequence {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "refusing to compact: snapshot sequence {} is ahead of the log's last sequence {}",
+                    snapshot.sequence, self.last_sequence
+                ),
+            ));
+        }
+
+        let (frames, _offset, corruption) = Self::scan_frames(&self.path)?;
+        if let Some(info) = corruption {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, info.to_string()));
+        }
+
+        let manifest = compaction::load_compaction_manifest(&self.path)?;
+        let base_head = Self::base_head_from_manifest(manifest.as_ref())?;
+
+        let mut cut_head = base_head;
+        let mut retained: Vec<&RawFrame> = Vec::new();
+        for frame in &frames {
+            if frame.event.sequence <= snapshot.sequence {
+                cut_head = chain_step(&cut_head, &frame.len_bytes, &frame.frame_bytes);
+            } else {
+                retained.push(frame);
+            }
+        }
+
+        let tmp_path = {
+            let mut os = self.path.as_os_str().to_os_string();
+            os.push(".compact_tmp");
+            PathBuf::from(os)
+        };
+        {
+            let mut file = File::create(&tmp_path)?;
+            for frame in &retained {
+                file.write_all(&frame.len_bytes)?;
+                file.write_all(&crc32(&frame.frame_bytes).to_le_bytes())?;
+                file.write_all(&frame.frame_bytes)?;
+            }
+            file.sync_all()?;
+        }
+        std::fs::rename(&tmp_path, &self.path)?;
+
+        let mut new_head = cut_head;
+        let mut chain_bytes = Vec::with_capacity(retained.len() * 32);
+        for frame in &retained {
+            new_head = chain_step(&new_head, &frame.len_bytes, &frame.frame_bytes);
+            chain_bytes.extend_from_slice(&new_head);
+        }
+        let chain_tmp_path = {
+            let mut os = self.chain_path.as_os_str().to_os_string();
+            os.push(".compact_tmp");
+            PathBuf::from(os)
+        };
+        {
+            let mut file = File::create(&chain_tmp_path)?;
+            file.write_all(&chain_bytes)?;
+            file.sync_all()?;
+        }
+        std::fs::rename(&chain_tmp_path, &self.chain_path)?;
+
+        compaction::write_compaction_manifest_atomic(
+            &self.path,
+            &CompactionManifest {
+                snapshot_seq: snapshot.sequence,
+                snapshot_hash: snapshot
+                    .hashes
+                    .get(&HashAlgorithm::Sha256)
+                    .cloned()
+                    .unwrap_or_default(),
+                chain_head: to_hex(&cut_head),
+            },
+        )?;
+
+        self.head = new_head;
+        Ok(())
+    }
+
+    fn read_chain_sidecar(path: &Path) -> io::Result<Vec<[u8; 32]>> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let bytes = std::fs::read(path)?;
+        if bytes.len() % 32 != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("chain sidecar length {} is not a multiple of 32", bytes.len()),
+            ));
+        }
+        Ok(bytes
+            .chunks_exact(32)
+            .map(|c| {
+                let mut digest = [0u8; 32];
+                digest.copy_from_slice(c);
+                digest
+            })
+            .collect())
+    }
+
+    /// Read all frames from a file, validating frame integrity
+    /// (length bound, checksum, protobuf decode) and retaining the raw
+    /// length/frame bytes needed to recompute the hash chain. Fails
+    /// hard at the first corrupt frame — callers that want to recover
+    /// the valid prefix instead of failing should use
+    /// `load_valid_prefix`.
+    fn read_all_raw_frames(path: &Path) -> io::Result<Vec<RawFrame>> {
+        let (frames, _offset, corruption) = Self::scan_frames(path)?;
+        if let Some(info) = corruption {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, info.to_string()));
+        }
+        Ok(frames)
+    }
+
+    /// Scan every frame before the first corrupt one. Unlike
+    /// `load_all_events`/`open`, this never fails on a torn tail — a
+    /// partial write interrupted by a crash leaves a truncated or
+    /// checksum-mismatched last frame, and everything written before it
+    /// is still trustworthy. Returns the recovered events, the exact
+    /// byte offset `repair_truncate` should rewrite the file to, and
+    /// `Some(CorruptionInfo)` describing what (if anything) was found
+    /// and discarded.
+    pub fn load_valid_prefix(
+        &self,
+    ) -> io::Result<(Vec<ProtoEventEnvelope>, u64, Option<CorruptionInfo>)> {
+        if !self.path.exists() {
+            return Ok((Vec::new(), 0, None));
+        }
+        let (frames, offset, corruption) = Self::scan_frames(&self.path)?;
+        let events = frames.into_iter().map(|f| f.event).collect();
+        Ok((events, offset, corruption))
+    }
+
+    /// Rewrite the log (and its `.chain` sidecar) back to the last
+    /// known-good frame boundary, discarding a torn tail left by a
+    /// crash mid-write. Recomputes `last_sequence` and `chain_head`
+    /// from what remains, picking up from the recorded
+    /// `CompactionManifest`'s floor/head the same way `open()` does —
+    /// otherwise recovering a compacted log would compute a chain head
+    /// as if the discarded prefix never existed.
+    pub fn repair_truncate(&mut self) -> io::Result<()> {
+        let manifest = compaction::load_compaction_manifest(&self.path)?;
+        let base_head = Self::base_head_from_manifest(manifest.as_ref())?;
+        let floor_sequence = manifest.as_ref().map(|m| m.snapshot_seq).unwrap_or(0);
+
+        let (frames, offset, _corruption) = Self::scan_frames(&self.path)?;
+
+        {
+            let file = OpenOptions::new().write(true).open(&self.path)?;
+            file.set_len(offset)?;
+            file.sync_all()?;
+        }
+
+        if self.chain_path.exists() {
+            let file = OpenOptions::new().write(true).open(&self.chain_path)?;
+            file.set_len(frames.len() as u64 * 32)?;
+            file.sync_all()?;
+        }
+
+        self.last_sequence = frames.last().map(|f| f.event.sequence).unwrap_or(floor_sequence);
+        self.head = Self::compute_chain_head(base_head, &frames);
+        Ok(())
+    }
+
+    /// Scan frames from `path` front to back, stopping at the first
+    /// length-bound violation, truncated frame, checksum mismatch, or
+    /// protobuf decode error rather than propagating it. Returns the
+    /// good prefix, the byte offset immediately after its last frame
+    /// (where a corrupt or absent frame begins), and `Some` describing
+    /// the stopping cause when the scan did not reach clean EOF.
+    fn scan_frames(path: &Path) -> io::Result<(Vec<RawFrame>, u64, Option<CorruptionInfo>)> {
         let file = File::open(path)?;
         let mut reader = BufReader::new(file);
-        let mut events = Vec::new();
+        let mut frames = Vec::new();
+        let mut offset: u64 = 0;
         let mut len_buf = [0u8; 4];
+        let mut crc_buf = [0u8; 4];
 
         loop {
             match reader.read_exact(&mut len_buf) {
                 Ok(()) => {}
-                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                    return Ok((frames, offset, None));
+                }
                 Err(e) => return Err(e),
             }
 
             let len = u32::from_le_bytes(len_buf) as usize;
             if len == 0 || len > 16 * 1024 * 1024 {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Invalid frame length: {}", len),
+                return Ok((
+                    frames,
+                    offset,
+                    Some(CorruptionInfo {
+                        offset,
+                        kind: CorruptionKind::InvalidLength(len),
+                    }),
+                ));
+            }
+
+            if reader.read_exact(&mut crc_buf).is_err() {
+                return Ok((
+                    frames,
+                    offset,
+                    Some(CorruptionInfo {
+                        offset,
+                        kind: CorruptionKind::TruncatedFrame,
+                    }),
                 ));
             }
 
-            let mut frame = vec![0u8; len];
-            reader.read_exact(&mut frame).map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Truncated frame at offset: {}", e),
-                )
-            })?;
+            let mut frame_bytes = vec![0u8; len];
+            if reader.read_exact(&mut frame_bytes).is_err() {
+                return Ok((
+                    frames,
+                    offset,
+                    Some(CorruptionInfo {
+                        offset,
+                        kind: CorruptionKind::TruncatedFrame,
+                    }),
+                ));
+            }
+
+            let expected_crc = u32::from_le_bytes(crc_buf);
+            if crc32(&frame_bytes) != expected_crc {
+                return Ok((
+                    frames,
+                    offset,
+                    Some(CorruptionInfo {
+                        offset,
+                        kind: CorruptionKind::ChecksumMismatch,
+                    }),
+                ));
+            }
 
-            let event = ProtoEventEnvelope::decode(frame.as_slice()).map_err(|e| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    format!("Protobuf decode error: {}", e),
-                )
-            })?;
+            let event = match ProtoEventEnvelope::decode(frame_bytes.as_slice()) {
+                Ok(event) => event,
+                Err(e) => {
+                    return Ok((
+                        frames,
+                        offset,
+                        Some(CorruptionInfo {
+                            offset,
+                            kind: CorruptionKind::DecodeError(e.to_string()),
+                        }),
+                    ));
+                }
+            };
 
-            events.push(event);
+            offset += 4 + 4 + frame_bytes.len() as u64;
+            frames.push(RawFrame {
+                event,
+                len_bytes: len_buf,
+                frame_bytes,
+            });
         }
+    }
+}
+
+/// The file-backed log is the default `StorageBackend` impl. `load_range`
+/// is O(total bytes) here — it reads the whole log and filters — since
+/// the length-prefixed format has no sequence index; a backend that
+/// wants O(range) should index by sequence (see the RocksDB impl).
+impl StorageBackend for EventStore {
+    fn append(&mut self, event: &ProtoEventEnvelope) -> io::Result<()> {
+        self.append_event(event)
+    }
+
+    fn last_sequence(&self) -> u64 {
+        self.last_sequence()
+    }
+
+    fn load_range(&self, from_seq: u64, to_seq: u64) -> io::Result<Vec<ProtoEventEnvelope>> {
+        Ok(self
+            .load_all_events()?
+            .into_iter()
+            .filter(|e| e.sequence >= from_seq && e.sequence <= to_seq)
+            .collect())
+    }
+
+    fn load_all(&self) -> io::Result<Vec<ProtoEventEnvelope>> {
+        self.load_all_events()
+    }
+
+    fn verify_integrity(&self) -> Result<(), String> {
+        self.verify_chain().map_err(|e| e.to_string())
+    }
 
-        Ok(events)
+    fn chain_heads(&self) -> io::Result<Option<Vec<[u8; 32]>>> {
+        Ok(Some(self.chain_head_sequence()?))
     }
 }
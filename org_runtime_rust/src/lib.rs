@@ -11,8 +11,18 @@
 pub mod proto_types;
 pub mod proto_bridge;
 pub mod event_store;
+pub mod event_store_rocksdb;
+pub mod storage_backend;
+pub mod compaction;
 pub mod replay;
 pub mod snapshot;
 pub mod snapshot_codec;
+pub mod snapshot_sig;
+pub mod snapshot_overlay;
+pub mod snapshot_migration;
+pub mod arrow_export;
+pub mod chain_signing;
+pub mod telemetry;
 pub mod session;
+pub mod submission_client;
 pub mod drift;
@@ -4,9 +4,14 @@
 //! kernel's EventEnvelope (which uses serde_json::Value payloads).
 //!
 //! CRITICAL: The JSON payload structure must exactly match what
-//! the kernel's transitions.rs expects to read.
+//! the kernel's transitions.rs expects to read. That contract is no
+//! longer enforced only by this comment — `org_engine_replica::schema`
+//! declares it as a machine-readable registry (`schema::registry_json`),
+//! and `try_proto_to_kernel` checks a converted payload against it
+//! before handing the event to a caller.
 
 use org_engine_replica::events::EventEnvelope;
+use org_engine_replica::schema::{self, SchemaError};
 use serde_json::{json, Value};
 
 use crate::proto_types::*;
@@ -122,6 +127,37 @@ pub fn proto_to_kernel(proto: &ProtoEventEnvelope) -> EventEnvelope {
     }
 }
 
+/// Non-panicking companion to `proto_to_kernel`.
+///
+/// Builds the kernel `EventEnvelope` exactly like `proto_to_kernel`,
+/// then validates its payload against `schema::REGISTRY` — an unknown
+/// field or a value of the wrong kind surfaces as `Err` instead of a
+/// handler deep in `transitions.rs` silently falling back to an
+/// `unwrap_or` default. Use this for protos built from untrusted or
+/// externally-produced input (e.g. `submission_client`'s networked
+/// producers); `proto_to_kernel` remains the trusted, panicking
+/// conversion for internally-generated protos.
+pub fn try_proto_to_kernel(proto: &ProtoEventEnvelope) -> Result<EventEnvelope, SchemaError> {
+    // Catch the sub-messages whose absence `proto_to_kernel` `expect`s.
+    let missing = match proto.event.as_ref().and_then(|e| e.kind.as_ref()) {
+        None => Some(("unknown", "event")),
+        Some(EventKind::InitializeConstants(ic)) if ic.constants.is_none() => {
+            Some(("initialize_constants", "constants"))
+        }
+        Some(EventKind::AddRole(ar)) if ar.role.is_none() => Some(("add_role", "role")),
+        _ => None,
+    };
+    if let Some((event_type, field)) = missing {
+        return Err(SchemaError::MissingField {
+            event_type: event_type.to_string(),
+            field: field.to_string(),
+        });
+    }
+    let kernel = proto_to_kernel(proto);
+    schema::validate_event(&kernel)?;
+    Ok(kernel)
+}
+
 /// Convert a kernel EventEnvelope to a protobuf EventEnvelope.
 ///
 /// Used for persisting events to the append-only binary log.
@@ -3,11 +3,19 @@
 //! Delegates all domain logic to the frozen Kernel v1.0.
 //! No shortcuts, no cached state logic.
 
+use std::io;
+use std::path::Path;
+
 use org_engine_replica::domain::OrgState;
 use org_engine_replica::engine::OrgEngine;
 use org_engine_replica::events::EventEnvelope;
 use org_engine_replica::hashing::canonical_hash;
 
+use crate::proto_bridge::proto_to_kernel;
+use crate::snapshot::{self, verify_snapshot_hash};
+use crate::snapshot_codec::decode_snapshot;
+use crate::storage_backend::StorageBackend;
+
 /// Rebuild the organizational state from a sequence of events.
 ///
 /// 1. Create fresh engine + state
@@ -34,3 +42,61 @@ pub fn rebuild_hash(events: &[EventEnvelope]) -> String {
     let (_, hash) = rebuild_state(events);
     hash
 }
+
+/// Rebuild state from a `[from_seq, to_seq]` subrange of a
+/// `StorageBackend`, without loading the rest of the log. With the file
+/// backend this still costs a full scan (see `EventStore::load_range`);
+/// with a sequence-indexed backend (e.g. RocksDB) it is proportional to
+/// the range itself. The range is replayed on a fresh engine, so it must
+/// start at sequence 1 (`initialize_constants`); a later `from_seq`
+/// panics in the kernel's sequence check.
+pub fn rebuild_range_from_backend(
+    backend: &dyn StorageBackend,
+    from_seq: u64,
+    to_seq: u64,
+) -> io::Result<(OrgState, String)> {
+    let proto_events = backend.load_range(from_seq, to_seq)?;
+    let events: Vec<EventEnvelope> = proto_events.iter().map(proto_to_kernel).collect();
+    Ok(rebuild_state(&events))
+}
+
+/// Rebuild state from a (possibly compacted) backend, resuming from the
+/// latest verified snapshot in `snapshot_dir` instead of always replaying
+/// from sequence 1.
+///
+/// Falls back to a full replay from genesis when `snapshot_dir` holds no
+/// snapshot. When one exists, its hash is re-verified before use — a
+/// corrupt or tampered snapshot is never trusted silently — and only the
+/// tail of the log after `snapshot.sequence` is replayed on top of it.
+pub fn bootstrap(backend: &dyn StorageBackend, snapshot_dir: &Path) -> io::Result<(OrgState, String)> {
+    let latest = snapshot::load_latest_snapshot(snapshot_dir)?;
+
+    let (mut engine, from_seq) = match latest {
+        Some(snap) => {
+            if !verify_snapshot_hash(&snap) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "snapshot hash verification failed",
+                ));
+            }
+            let state = decode_snapshot(&snap.canonical_json)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            (OrgEngine::resume_from_state(state, snap.sequence), snap.sequence)
+        }
+        None => {
+            let mut engine = OrgEngine::new();
+            engine.initialize_state();
+            (engine, 0)
+        }
+    };
+
+    let proto_events = backend.load_range(from_seq + 1, u64::MAX)?;
+    let events: Vec<EventEnvelope> = proto_events.iter().map(proto_to_kernel).collect();
+    for evt in &events {
+        engine.apply_event(evt);
+    }
+
+    let state = engine.state().clone();
+    let hash = canonical_hash(&state);
+    Ok((state, hash))
+}
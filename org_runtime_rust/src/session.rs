@@ -7,32 +7,79 @@
 //!   1. engine.apply_event(event)  — may panic on invariant violation
 //!   2. event_store.append_event() — only if step 1 succeeded
 //!   3. snapshot if interval reached
+//!   4. sign the new chain head, if a signing key is installed
 
+use std::fmt;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use ed25519_dalek::SigningKey;
+
 use org_engine_replica::domain::{OrgState, TransitionResult};
 use org_engine_replica::engine::OrgEngine;
 use org_engine_replica::events::EventEnvelope;
 use org_engine_replica::hashing::canonical_hash;
 
+use crate::chain_signing::{self, ChainHeadSignature};
 use crate::event_store::EventStore;
 use crate::proto_bridge::{kernel_to_proto, proto_to_kernel};
 use crate::replay;
 use crate::snapshot;
+use crate::snapshot_sig::PublicKey;
+use crate::storage_backend::StorageBackend;
+
+/// Why `Session::verify_chain` (and therefore `replay_full`, which calls
+/// it first) refused to trust the log.
+#[derive(Debug)]
+pub enum ChainVerifyError {
+    /// The backing `StorageBackend`'s own integrity check failed (e.g.
+    /// `EventStore`'s hash-chain sidecar diverged).
+    Integrity(String),
+    Io(std::io::Error),
+    /// A session with a signing key installed has a different number of
+    /// stored signatures than chain heads to check them against.
+    SignatureCountMismatch,
+    /// The signature over the chain head at this index (0-based, in
+    /// append order) did not verify against the session's public key.
+    SignatureDiverged { index: u64 },
+}
+
+impl fmt::Display for ChainVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChainVerifyError::Integrity(msg) => write!(f, "chain integrity check failed: {}", msg),
+            ChainVerifyError::Io(e) => write!(f, "io error during chain verification: {}", e),
+            ChainVerifyError::SignatureCountMismatch => {
+                write!(f, "signature sidecar length does not match the chain length")
+            }
+            ChainVerifyError::SignatureDiverged { index } => {
+                write!(f, "signature over chain head #{} failed to verify", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChainVerifyError {}
 
 /// An isolated simulation session with its own event log and state.
+///
+/// `event_store` is boxed behind `StorageBackend` so a session can be
+/// backed by the default file log or by a custom backend (e.g. RocksDB)
+/// without any other method on `Session` changing.
 pub struct Session {
     session_id: String,
     base_dir: PathBuf,
     engine: OrgEngine,
-    event_store: EventStore,
+    event_store: Box<dyn StorageBackend + Send>,
     snapshot_interval: u64,
     current_sequence: u64,
+    signing_key: Option<SigningKey>,
 }
 
 impl Session {
-    /// Create a new session in the given base directory.
+    /// Create a new session in the given base directory, backed by the
+    /// default file-based `EventStore`.
     ///
     /// Directory structure:
     ///   <base_dir>/<session_id>/events.log
@@ -46,6 +93,23 @@ impl Session {
         let events_path = session_dir.join("events.log");
 
         let event_store = EventStore::open(&events_path)?;
+        Self::with_backend(
+            session_dir,
+            session_id,
+            snapshot_interval,
+            Box::new(event_store),
+        )
+    }
+
+    /// Create a new session backed by a caller-supplied `StorageBackend`
+    /// (e.g. a RocksDB-backed log), replaying whatever events it already
+    /// holds before the session becomes usable.
+    pub fn with_backend(
+        session_dir: PathBuf,
+        session_id: &str,
+        snapshot_interval: u64,
+        event_store: Box<dyn StorageBackend + Send>,
+    ) -> std::io::Result<Self> {
         let last_seq = event_store.last_sequence();
 
         let mut engine = OrgEngine::new();
@@ -53,7 +117,7 @@ impl Session {
 
         // Replay existing events if any
         if last_seq > 0 {
-            let proto_events = event_store.load_all_events()?;
+            let proto_events = event_store.load_all()?;
             for pe in &proto_events {
                 let ke = proto_to_kernel(pe);
                 engine.apply_event(&ke);
@@ -67,17 +131,78 @@ impl Session {
             event_store,
             snapshot_interval,
             current_sequence: last_seq,
+            signing_key: None,
         })
     }
 
+    /// Path of the signature sidecar: one JSON-encoded `ChainHeadSignature`
+    /// per line, in append order, written alongside the event log.
+    fn sig_path(&self) -> PathBuf {
+        self.base_dir.join("events.log.sig")
+    }
+
+    /// Install a signing key: every event applied from this point on has
+    /// its resulting chain head signed and appended to the signature
+    /// sidecar. Replaces any previously installed key.
+    pub fn set_signing_key(&mut self, signing_key: SigningKey) {
+        self.signing_key = Some(signing_key);
+    }
+
+    /// Sign the backend's current chain head (if both a key is installed
+    /// and the backend tracks a chain) and append the signature to the
+    /// sidecar. Called after every successful apply.
+    fn sign_current_head(&self) {
+        let Some(signing_key) = &self.signing_key else {
+            return;
+        };
+        let Ok(Some(heads)) = self.event_store.chain_heads() else {
+            return;
+        };
+        let Some(head) = heads.last() else {
+            return;
+        };
+        let signature = chain_signing::sign_chain_head(head, signing_key);
+        let line = serde_json::to_string(&signature)
+            .expect("chain head signature serialization failed");
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.sig_path())
+            .expect("Signature sidecar write failed");
+        writeln!(file, "{}", line).expect("Signature sidecar write failed");
+        file.sync_all().expect("Signature sidecar sync failed");
+    }
+
     /// Apply a single event: validate via kernel, then persist.
     ///
     /// Returns (state_clone, transition_result).
     /// Panics if kernel rejects the event (invariant violation, sequence error).
+    ///
+    /// Under the `telemetry` feature, the whole apply is wrapped in a span
+    /// carrying `session_id`/`sequence`/`event_type`; a panic below is
+    /// recorded against the invariant-rejection counter before it resumes
+    /// unwinding — see `telemetry::traced_apply_event`.
     pub fn apply_event(
         &mut self,
         event: &EventEnvelope,
     ) -> (OrgState, TransitionResult) {
+        #[cfg(feature = "telemetry")]
+        {
+            let session_id = self.session_id.clone();
+            let sequence = event.sequence;
+            let event_type = event.event_type.clone();
+            crate::telemetry::traced_apply_event(&session_id, sequence, &event_type, || {
+                self.apply_event_inner(event)
+            })
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            self.apply_event_inner(event)
+        }
+    }
+
+    fn apply_event_inner(&mut self, event: &EventEnvelope) -> (OrgState, TransitionResult) {
         // Step 1: Apply to kernel (may panic)
         let (state, result) = self.engine.apply_event(event);
         let state_clone = state.clone();
@@ -86,7 +211,7 @@ impl Session {
         // Step 2: Persist to event log (only if step 1 succeeded)
         let proto = kernel_to_proto(event);
         self.event_store
-            .append_event(&proto)
+            .append(&proto)
             .expect("Event store write failed");
         self.current_sequence = event.sequence;
 
@@ -94,17 +219,156 @@ impl Session {
         if self.snapshot_interval > 0
             && event.sequence % self.snapshot_interval == 0
         {
-            let snap_dir = self.base_dir.join("snapshots");
-            snapshot::save_snapshot(&snap_dir, event.sequence, &state_clone)
-                .expect("Snapshot save failed");
+            self.save_snapshot_traced(event.sequence, &state_clone);
         }
 
+        self.sign_current_head();
+
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::record_state_gauges(&state_clone);
+
         (state_clone, result_clone)
     }
 
+    /// Non-panicking companion to `apply_event`.
+    ///
+    /// Delegates to `OrgEngine::try_apply_event` instead of the panicking
+    /// `apply_event`, so a sequence/schema/constants-first violation or a
+    /// malformed payload surfaces as `Err` instead of aborting the
+    /// process — the path networked producers (see `submission_client`)
+    /// drive instead of the local-only `apply_event`. The event log and
+    /// auto-snapshot are only touched on success, same as `apply_event`.
+    pub fn try_apply_event(
+        &mut self,
+        event: &EventEnvelope,
+    ) -> Result<(OrgState, TransitionResult), org_engine_replica::error::KernelError> {
+        #[cfg(feature = "telemetry")]
+        {
+            let session_id = self.session_id.clone();
+            let sequence = event.sequence;
+            let event_type = event.event_type.clone();
+            crate::telemetry::traced_try_apply_event(&session_id, sequence, &event_type, || {
+                self.try_apply_event_inner(event)
+            })
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            self.try_apply_event_inner(event)
+        }
+    }
+
+    fn try_apply_event_inner(
+        &mut self,
+        event: &EventEnvelope,
+    ) -> Result<(OrgState, TransitionResult), org_engine_replica::error::KernelError> {
+        let (state, result) = self.engine.try_apply_event(event)?;
+        let state_clone = state.clone();
+        let result_clone = result.clone();
+
+        let proto = kernel_to_proto(event);
+        self.event_store
+            .append(&proto)
+            .expect("Event store write failed");
+        self.current_sequence = event.sequence;
+
+        if self.snapshot_interval > 0 && event.sequence % self.snapshot_interval == 0 {
+            self.save_snapshot_traced(event.sequence, &state_clone);
+        }
+
+        self.sign_current_head();
+
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::record_state_gauges(&state_clone);
+
+        Ok((state_clone, result_clone))
+    }
+
+    /// Save a snapshot at `sequence`, wrapped in a span under the
+    /// `telemetry` feature (see `telemetry::traced_snapshot`).
+    fn save_snapshot_traced(&self, sequence: u64, state: &OrgState) {
+        let snap_dir = self.base_dir.join("snapshots");
+        #[cfg(feature = "telemetry")]
+        {
+            let session_id = self.session_id.clone();
+            crate::telemetry::traced_snapshot(&session_id, sequence, || {
+                snapshot::save_snapshot(&snap_dir, sequence, state)
+            })
+            .expect("Snapshot save failed");
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            snapshot::save_snapshot(&snap_dir, sequence, state).expect("Snapshot save failed");
+        }
+    }
+
+    /// Recompute the event log's hash chain (and, if a signing key is
+    /// installed, every stored signature) from scratch and check it
+    /// matches what's on disk, returning the first divergence found.
+    /// `replay_full` calls this before trusting the log, so a corrupted
+    /// or reordered log is rejected before it can produce a bogus
+    /// `rebuild_hash` instead of silently replaying tampered events.
+    pub fn verify_chain(&self) -> Result<(), ChainVerifyError> {
+        self.event_store
+            .verify_integrity()
+            .map_err(ChainVerifyError::Integrity)?;
+
+        let Some(signing_key) = &self.signing_key else {
+            return Ok(());
+        };
+
+        let heads = self
+            .event_store
+            .chain_heads()
+            .map_err(ChainVerifyError::Io)?
+            .unwrap_or_default();
+
+        let sig_text = std::fs::read_to_string(self.sig_path()).unwrap_or_default();
+        let signatures: Vec<ChainHeadSignature> = sig_text
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_str(line).expect("chain signature sidecar line is not valid JSON")
+            })
+            .collect();
+
+        if signatures.len() != heads.len() {
+            return Err(ChainVerifyError::SignatureCountMismatch);
+        }
+
+        let trusted = PublicKey::from_signing_key(signing_key);
+        for (index, (signature, head)) in signatures.iter().zip(heads.iter()).enumerate() {
+            if !chain_signing::verify_chain_head_signature(head, signature, &trusted) {
+                return Err(ChainVerifyError::SignatureDiverged {
+                    index: index as u64,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Full replay from event log — reset engine and replay all events.
+    ///
+    /// Under the `telemetry` feature, the whole replay runs inside a span
+    /// carrying `session_id`, and the resulting state's gauges are
+    /// recorded on success (see `telemetry::traced_replay`).
     pub fn replay_full(&mut self) -> std::io::Result<(OrgState, String)> {
-        let proto_events = self.event_store.load_all_events()?;
+        #[cfg(feature = "telemetry")]
+        {
+            let session_id = self.session_id.clone();
+            crate::telemetry::traced_replay(&session_id, || self.replay_full_inner())
+        }
+        #[cfg(not(feature = "telemetry"))]
+        {
+            self.replay_full_inner()
+        }
+    }
+
+    fn replay_full_inner(&mut self) -> std::io::Result<(OrgState, String)> {
+        self.verify_chain()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let proto_events = self.event_store.load_all()?;
         let kernel_events: Vec<EventEnvelope> =
             proto_events.iter().map(proto_to_kernel).collect();
 
@@ -117,6 +381,9 @@ impl Session {
             self.engine.apply_event(ke);
         }
 
+        #[cfg(feature = "telemetry")]
+        crate::telemetry::record_state_gauges(&state);
+
         Ok((state, hash))
     }
 
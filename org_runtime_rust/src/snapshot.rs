@@ -1,50 +1,164 @@
 //! Snapshot layer — deterministic state snapshots.
 //!
-//! Snapshots contain canonical JSON + hash for verification.
+//! Snapshots contain canonical JSON + a `hashes` map for verification.
 //! No timestamps in snapshot content (determinism).
 //!
 //! If snapshot hash doesn't match replay, trigger full replay.
 
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs::{self, File};
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-use sha2::{Digest, Sha256};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use sha2::{Digest, Sha256, Sha512};
 
-use org_engine_replica::hashing::{canonical_hash, canonical_serialize};
 use org_engine_replica::domain::OrgState;
+use org_engine_replica::hashing::canonical_serialize;
+
+/// A digest algorithm a snapshot can be hashed with. New variants should
+/// be added here and to `digest_hex` together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    /// Lowercase-hex digest of `bytes` under this algorithm.
+    fn digest_hex(&self, bytes: &[u8]) -> String {
+        match self {
+            HashAlgorithm::Sha256 => Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect(),
+            HashAlgorithm::Sha512 => Sha512::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect(),
+        }
+    }
+}
+
+impl fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for HashAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sha256" => Ok(HashAlgorithm::Sha256),
+            "sha512" => Ok(HashAlgorithm::Sha512),
+            other => Err(format!("unknown hash algorithm: {}", other)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HashAlgorithm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The default algorithm set new snapshots are hashed with.
+pub const DEFAULT_ALGORITHMS: &[HashAlgorithm] = &[HashAlgorithm::Sha256, HashAlgorithm::Sha512];
 
 /// Snapshot on-disk format.
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Snapshot {
     /// Sequence number at which this snapshot was taken.
     pub sequence: u64,
     /// Canonical JSON of the state (UTF-8).
     pub canonical_json: String,
-    /// SHA-256 of the canonical JSON.
-    pub hash: String,
+    /// Digest of the canonical JSON, keyed by algorithm. An empty map
+    /// (e.g. from a file whose hashes were stripped) fails
+    /// `verify_snapshot_hash`, so removing it cannot strip integrity checks.
+    pub hashes: BTreeMap<HashAlgorithm, String>,
     /// Kernel version at snapshot time.
     pub kernel_version: u32,
 }
 
-/// Save a deterministic snapshot of the current state.
-pub fn save_snapshot(
+/// Intermediate on-disk shape, accepted for backward compatibility with
+/// snapshots written before the `hashes` map existed.
+#[derive(Deserialize)]
+struct SnapshotOnDisk {
+    sequence: u64,
+    canonical_json: String,
+    #[serde(default)]
+    hashes: BTreeMap<HashAlgorithm, String>,
+    /// Legacy single-algorithm field, folded into `hashes` as `Sha256`.
+    #[serde(default)]
+    hash: Option<String>,
+    kernel_version: u32,
+}
+
+impl<'de> Deserialize<'de> for Snapshot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut raw = SnapshotOnDisk::deserialize(deserializer)?;
+        if let Some(legacy_hash) = raw.hash.take() {
+            raw.hashes.entry(HashAlgorithm::Sha256).or_insert(legacy_hash);
+        }
+        Ok(Snapshot {
+            sequence: raw.sequence,
+            canonical_json: raw.canonical_json,
+            hashes: raw.hashes,
+            kernel_version: raw.kernel_version,
+        })
+    }
+}
+
+/// Compute digests of `bytes` under a set of algorithms.
+fn compute_hashes_bytes(bytes: &[u8], algorithms: &[HashAlgorithm]) -> BTreeMap<HashAlgorithm, String> {
+    algorithms
+        .iter()
+        .map(|algo| (*algo, algo.digest_hex(bytes)))
+        .collect()
+}
+
+/// Compute `hashes` for a canonical JSON string under a set of algorithms.
+fn compute_hashes(canonical_json: &str, algorithms: &[HashAlgorithm]) -> BTreeMap<HashAlgorithm, String> {
+    compute_hashes_bytes(canonical_json.as_bytes(), algorithms)
+}
+
+/// Save a deterministic snapshot, hashed with `DEFAULT_ALGORITHMS`.
+pub fn save_snapshot(dir: &Path, sequence: u64, state: &OrgState) -> io::Result<PathBuf> {
+    save_snapshot_with_algorithms(dir, sequence, state, DEFAULT_ALGORITHMS)
+}
+
+/// Save a deterministic snapshot of the current state, hashed with
+/// exactly the given `algorithms`.
+pub fn save_snapshot_with_algorithms(
     dir: &Path,
     sequence: u64,
     state: &OrgState,
+    algorithms: &[HashAlgorithm],
 ) -> io::Result<PathBuf> {
     fs::create_dir_all(dir)?;
 
     let canonical_bytes = canonical_serialize(state);
     let canonical_json =
         String::from_utf8(canonical_bytes).expect("canonical JSON is always valid UTF-8");
-    let hash = canonical_hash(state);
+    let hashes = compute_hashes(&canonical_json, algorithms);
 
     let snap = Snapshot {
         sequence,
         canonical_json,
-        hash,
+        hashes,
         kernel_version: org_engine_replica::KERNEL_VERSION,
     };
 
@@ -58,6 +172,15 @@ pub fn save_snapshot(
     file.write_all(content.as_bytes())?;
     file.sync_all()?;
 
+    update_manifest_entry(
+        dir,
+        ManifestEntry {
+            sequence,
+            length: content.len() as u64,
+            hashes: compute_hashes_bytes(content.as_bytes(), algorithms),
+        },
+    )?;
+
     Ok(path)
 }
 
@@ -114,9 +237,267 @@ pub fn load_latest_snapshot(dir: &Path) -> io::Result<Option<Snapshot>> {
 }
 
 /// Verify a snapshot's internal hash consistency.
-/// Returns true if the hash matches the canonical JSON content.
+///
+/// Recomputes and compares *every* algorithm present in `hashes`,
+/// failing if any mismatches. An empty `hashes` map is itself a
+/// failure — it would let an attacker strip all integrity checks by
+/// deleting the map's contents.
 pub fn verify_snapshot_hash(snap: &Snapshot) -> bool {
-    let digest = Sha256::digest(snap.canonical_json.as_bytes());
-    let computed: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
-    computed == snap.hash
+    if snap.hashes.is_empty() {
+        return false;
+    }
+
+    snap.hashes
+        .iter()
+        .all(|(algo, expected)| &algo.digest_hex(snap.canonical_json.as_bytes()) == expected)
+}
+
+// ---------------------------------------------------------------------------
+// Rollback / version-monotonicity protection
+// ---------------------------------------------------------------------------
+
+/// A caller-persisted record of the last snapshot state this process
+/// trusted. Passed back into `load_latest_verified` on the next load so
+/// a swapped-in older snapshot file can be detected and rejected.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TrustRecord {
+    pub last_sequence: u64,
+    pub kernel_version: u32,
+}
+
+/// A `load_latest_verified` rejection.
+#[derive(Debug)]
+pub enum TrustError {
+    /// No snapshot exists in `dir`.
+    NotFound,
+    /// The candidate's `sequence` is older than the trusted record — a
+    /// rollback/freeze attack.
+    Rollback { candidate: u64, trusted: u64 },
+    /// The candidate's `kernel_version` is newer than this binary
+    /// understands.
+    UnknownFutureVersion { candidate: u32, current: u32 },
+    /// The candidate's `kernel_version` is older than the trusted
+    /// record's and migration was not explicitly allowed.
+    DowngradeWithoutMigration { candidate: u32, trusted: u32 },
+    /// The candidate failed its own `hashes` self-check.
+    HashMismatch,
+    Io(io::Error),
+}
+
+impl fmt::Display for TrustError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrustError::NotFound => write!(f, "no snapshot found"),
+            TrustError::Rollback { candidate, trusted } => write!(
+                f,
+                "rollback rejected: candidate sequence {} is behind trusted sequence {}",
+                candidate, trusted
+            ),
+            TrustError::UnknownFutureVersion { candidate, current } => write!(
+                f,
+                "candidate kernel_version {} is newer than this binary's {}",
+                candidate, current
+            ),
+            TrustError::DowngradeWithoutMigration { candidate, trusted } => write!(
+                f,
+                "candidate kernel_version {} is older than trusted {} and migration was not allowed",
+                candidate, trusted
+            ),
+            TrustError::HashMismatch => write!(f, "candidate snapshot failed its own hash check"),
+            TrustError::Io(e) => write!(f, "io error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TrustError {}
+
+impl From<io::Error> for TrustError {
+    fn from(e: io::Error) -> Self {
+        TrustError::Io(e)
+    }
+}
+
+/// Load the latest snapshot in `dir`, rejecting rollback, unknown-future,
+/// and un-migrated-downgrade candidates against `trust`, and verifying
+/// its own hash before returning. On success, returns the snapshot and
+/// an updated `TrustRecord` the caller should persist in place of `trust`.
+pub fn load_latest_verified(
+    dir: &Path,
+    trust: &TrustRecord,
+    allow_migration: bool,
+) -> Result<(Snapshot, TrustRecord), TrustError> {
+    let snap = load_latest_snapshot(dir)?.ok_or(TrustError::NotFound)?;
+
+    if snap.sequence < trust.last_sequence {
+        return Err(TrustError::Rollback {
+            candidate: snap.sequence,
+            trusted: trust.last_sequence,
+        });
+    }
+
+    if snap.kernel_version > org_engine_replica::KERNEL_VERSION {
+        return Err(TrustError::UnknownFutureVersion {
+            candidate: snap.kernel_version,
+            current: org_engine_replica::KERNEL_VERSION,
+        });
+    }
+
+    if snap.kernel_version < trust.kernel_version && !allow_migration {
+        return Err(TrustError::DowngradeWithoutMigration {
+            candidate: snap.kernel_version,
+            trusted: trust.kernel_version,
+        });
+    }
+
+    if !verify_snapshot_hash(&snap) {
+        return Err(TrustError::HashMismatch);
+    }
+
+    let updated = TrustRecord {
+        last_sequence: snap.sequence,
+        kernel_version: snap.kernel_version,
+    };
+    Ok((snap, updated))
+}
+
+// ---------------------------------------------------------------------------
+// Snapshot-set manifest
+// ---------------------------------------------------------------------------
+
+const MANIFEST_FILENAME: &str = "manifest.json";
+
+/// One snapshot's recorded size and digests in the directory manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub sequence: u64,
+    /// Byte length of the snapshot file on disk.
+    pub length: u64,
+    /// Digests of the snapshot file's raw bytes, keyed by algorithm.
+    pub hashes: BTreeMap<HashAlgorithm, String>,
+}
+
+/// `manifest.json`: every snapshot in a directory, keyed by sequence.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    pub entries: BTreeMap<u64, ManifestEntry>,
+}
+
+fn load_manifest(dir: &Path) -> io::Result<Manifest> {
+    let path = dir.join(MANIFEST_FILENAME);
+    if !path.exists() {
+        return Ok(Manifest::default());
+    }
+    let content = fs::read_to_string(&path)?;
+    serde_json::from_str(&content).map_err(|e| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("bad manifest: {}", e))
+    })
+}
+
+/// Write `manifest` atomically: write to a temp file in the same
+/// directory, fsync, then rename over the target — a concurrent reader
+/// never observes a partially-written manifest.
+fn write_manifest_atomic(dir: &Path, manifest: &Manifest) -> io::Result<()> {
+    let path = dir.join(MANIFEST_FILENAME);
+    let tmp_path = dir.join(format!("{}.tmp", MANIFEST_FILENAME));
+    let content = serde_json::to_string(manifest).expect("manifest serialization failed");
+
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Insert or replace `entry` in the directory's manifest and persist it
+/// atomically. Called by `save_snapshot_with_algorithms` after the
+/// snapshot file itself has been written and fsynced.
+fn update_manifest_entry(dir: &Path, entry: ManifestEntry) -> io::Result<()> {
+    let mut manifest = load_manifest(dir)?;
+    manifest.entries.insert(entry.sequence, entry);
+    write_manifest_atomic(dir, &manifest)
+}
+
+/// Result of auditing a directory's snapshots against its manifest.
+#[derive(Debug, Clone, Default)]
+pub struct ManifestReport {
+    /// Sequences listed in `manifest.json` with no snapshot file on disk.
+    pub missing_on_disk: Vec<u64>,
+    /// Sequences with a snapshot file on disk absent from `manifest.json`.
+    pub missing_from_manifest: Vec<u64>,
+    /// Sequences present in both, but whose file length or a recomputed
+    /// digest disagrees with its manifest entry.
+    pub mismatched: Vec<u64>,
+}
+
+impl ManifestReport {
+    pub fn is_clean(&self) -> bool {
+        self.missing_on_disk.is_empty()
+            && self.missing_from_manifest.is_empty()
+            && self.mismatched.is_empty()
+    }
+}
+
+/// Audit every `snapshot_NNNNNN.json` file in `dir` against its
+/// `manifest.json` in a single pass: which sequences are missing on
+/// disk, which are missing from the manifest, and which disagree on
+/// byte length or a recomputed digest.
+pub fn verify_manifest(dir: &Path) -> io::Result<ManifestReport> {
+    let manifest = load_manifest(dir)?;
+    let mut on_disk: BTreeMap<u64, PathBuf> = BTreeMap::new();
+
+    if dir.exists() {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let name_str = name.to_string_lossy().into_owned();
+            if let Some(seq_str) = name_str
+                .strip_prefix("snapshot_")
+                .and_then(|s| s.strip_suffix(".json"))
+            {
+                if let Ok(seq) = seq_str.parse::<u64>() {
+                    on_disk.insert(seq, dir.join(&name_str));
+                }
+            }
+        }
+    }
+
+    let mut report = ManifestReport::default();
+
+    for &seq in manifest.entries.keys() {
+        if !on_disk.contains_key(&seq) {
+            report.missing_on_disk.push(seq);
+        }
+    }
+    for &seq in on_disk.keys() {
+        if !manifest.entries.contains_key(&seq) {
+            report.missing_from_manifest.push(seq);
+        }
+    }
+
+    for (seq, path) in &on_disk {
+        let Some(entry) = manifest.entries.get(seq) else {
+            continue;
+        };
+
+        let bytes = match fs::read(path) {
+            Ok(b) => b,
+            Err(_) => {
+                report.mismatched.push(*seq);
+                continue;
+            }
+        };
+
+        if bytes.len() as u64 != entry.length {
+            report.mismatched.push(*seq);
+            continue;
+        }
+
+        let algorithms: Vec<HashAlgorithm> = entry.hashes.keys().copied().collect();
+        let recomputed = compute_hashes_bytes(&bytes, &algorithms);
+        if recomputed != entry.hashes {
+            report.mismatched.push(*seq);
+        }
+    }
+
+    Ok(report)
 }
@@ -10,7 +10,7 @@
 
 use std::fmt;
 use std::fs;
-use std::io;
+use std::io::{self, Read};
 use std::path::Path;
 
 use sha2::{Digest, Sha256};
@@ -18,6 +18,21 @@ use sha2::{Digest, Sha256};
 use org_engine_replica::domain::OrgState;
 use org_engine_replica::invariants::try_validate_invariants;
 
+/// Default cap on snapshot file size read by `import_snapshot_from_file`
+/// (64 MiB) — large enough for any realistic `OrgState`, small enough
+/// that a hostile snapshot store can't exhaust memory on read.
+pub const DEFAULT_MAX_SNAPSHOT_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Reserved Windows device names — illegal as a path component on any
+/// platform-portable path, regardless of the host OS, with or without
+/// an extension (`NUL.json` is just as illegal as `NUL`). Shared with
+/// `snapshot_overlay.rs`'s `%include` resolution, which needs the same
+/// path-sanitization rules.
+pub(crate) const RESERVED_WINDOWS_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
 // ---------------------------------------------------------------------------
 // Error type
 // ---------------------------------------------------------------------------
@@ -126,17 +141,96 @@ pub fn export_snapshot_to_file(
     Ok(())
 }
 
-/// Import an OrgState from a JSON file.
+/// Import an OrgState from a JSON file, capped at
+/// `DEFAULT_MAX_SNAPSHOT_BYTES`.
 ///
 /// Reads the file, deserializes, and validates invariants.
 /// Fails on malformed JSON, missing fields, or invariant violations.
 pub fn import_snapshot_from_file(
     path: &Path,
 ) -> Result<OrgState, SnapshotError> {
-    let content = fs::read_to_string(path)?;
+    import_snapshot_from_file_bounded(path, DEFAULT_MAX_SNAPSHOT_BYTES)
+}
+
+/// Import an OrgState from a JSON file, reading at most `max_bytes`.
+///
+/// Validates the final path component against a denylist (`.`, `..`,
+/// empty, reserved Windows device names, control characters) before
+/// opening, then reads through a bounded reader — a file larger than
+/// `max_bytes` fails with `IoError` rather than being allocated in full.
+pub fn import_snapshot_from_file_bounded(
+    path: &Path,
+    max_bytes: u64,
+) -> Result<OrgState, SnapshotError> {
+    validate_path_component(path).map_err(io_to_snapshot_error)?;
+    let content = read_bounded(path, max_bytes).map_err(io_to_snapshot_error)?;
     restore_snapshot(&content)
 }
 
+fn io_to_snapshot_error(e: io::Error) -> SnapshotError {
+    SnapshotError::IoError(e.to_string())
+}
+
+/// Reject illegal final path components: `.`, `..`, empty, reserved
+/// Windows device names (case-insensitive, with or without extension),
+/// and control characters. Shared with `snapshot_overlay.rs`, which
+/// applies the same check before resolving an `%include` path.
+pub(crate) fn validate_path_component(path: &Path) -> io::Result<()> {
+    let name = path.file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("path has no valid file name: {}", path.display()),
+        )
+    })?;
+
+    if name.is_empty() || name == "." || name == ".." {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("illegal path component: {:?}", name),
+        ));
+    }
+    if name.chars().any(|c| c.is_control()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("path component contains control characters: {:?}", name),
+        ));
+    }
+    let stem = name.split('.').next().unwrap_or(name);
+    if RESERVED_WINDOWS_NAMES
+        .iter()
+        .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("reserved device name in path component: {:?}", name),
+        ));
+    }
+    Ok(())
+}
+
+/// Read a file's contents as a UTF-8 string, capped at `max_bytes`.
+/// Fails once the cap is exceeded instead of allocating the full
+/// (potentially unbounded) file contents. Shared with
+/// `snapshot_overlay.rs`'s `%include` resolution.
+pub(crate) fn read_bounded(path: &Path, max_bytes: u64) -> io::Result<String> {
+    let file = fs::File::open(path)?;
+    let mut limited = file.take(max_bytes + 1);
+    let mut buf = Vec::new();
+    limited.read_to_end(&mut buf)?;
+    if buf.len() as u64 > max_bytes {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "snapshot at {} exceeds max_bytes cap of {}",
+                path.display(),
+                max_bytes
+            ),
+        ));
+    }
+    String::from_utf8(buf)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+}
+
 // ---------------------------------------------------------------------------
 // Hash
 // ---------------------------------------------------------------------------
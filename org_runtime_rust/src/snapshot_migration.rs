@@ -0,0 +1,96 @@
+//! Snapshot Migration Pipeline — forward-load older `kernel_version`s.
+//!
+//! `decode_snapshot` does strict `deny_unknown_fields` deserialization,
+//! so a snapshot whose `canonical_json` body was produced under an
+//! older `KERNEL_VERSION` with a since-changed schema fails hard. This
+//! module is a registry of `(from_version, to_version)` migrations —
+//! each a `Value -> Result<Value>` transform — applied in sequence to
+//! the raw JSON before it reaches strict typed deserialization, so old
+//! snapshot archives stay loadable across schema evolution instead of
+//! being rejected outright.
+
+use serde_json::Value;
+
+use org_engine_replica::domain::OrgState;
+use org_engine_replica::invariants::try_validate_invariants;
+use org_engine_replica::KERNEL_VERSION;
+
+use crate::snapshot::Snapshot;
+use crate::snapshot_codec::{decode_snapshot, SnapshotError};
+
+/// One raw-JSON transform taking a `canonical_json` body from
+/// `from_version`'s schema to `to_version`'s.
+pub type Migration = fn(Value) -> Result<Value, SnapshotError>;
+
+/// One registered migration step.
+pub struct MigrationStep {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub migrate: Migration,
+}
+
+/// Registered migrations. Empty today — Kernel v1's schema has not
+/// changed — but the pipeline exists so a future kernel_v2/v3 schema
+/// change can add a step here instead of stranding old archives.
+pub const MIGRATIONS: &[MigrationStep] = &[];
+
+/// Resolve the ordered chain of migrations connecting `from_version` to
+/// `to_version`, failing if no registered step continues the chain or
+/// if `from_version` is newer than `to_version` (an unknown future
+/// format this binary cannot understand at all).
+fn migration_path(
+    from_version: u32,
+    to_version: u32,
+) -> Result<Vec<&'static MigrationStep>, SnapshotError> {
+    if from_version > to_version {
+        return Err(SnapshotError::DeserializationError(format!(
+            "snapshot kernel_version {} is newer than this binary's {}",
+            from_version, to_version
+        )));
+    }
+
+    let mut path = Vec::new();
+    let mut current = from_version;
+    while current < to_version {
+        let step = MIGRATIONS
+            .iter()
+            .find(|m| m.from_version == current)
+            .ok_or_else(|| {
+                SnapshotError::DeserializationError(format!(
+                    "no migration path from kernel_version {} to {} (stuck at {})",
+                    from_version, to_version, current
+                ))
+            })?;
+        path.push(step);
+        current = step.to_version;
+    }
+    Ok(path)
+}
+
+/// Decode a `Snapshot` envelope's `canonical_json` body into an
+/// `OrgState`, migrating it forward from its recorded `kernel_version`
+/// to the current `KERNEL_VERSION` first if needed, then validating
+/// invariants exactly as `restore_snapshot` does.
+pub fn restore_snapshot_migrated(snap: &Snapshot) -> Result<OrgState, SnapshotError> {
+    let path = migration_path(snap.kernel_version, KERNEL_VERSION)?;
+
+    if path.is_empty() {
+        let state = decode_snapshot(&snap.canonical_json)?;
+        try_validate_invariants(&state).map_err(SnapshotError::InvariantViolation)?;
+        return Ok(state);
+    }
+
+    let mut value: Value = serde_json::from_str(&snap.canonical_json)
+        .map_err(|e| SnapshotError::DeserializationError(e.to_string()))?;
+
+    for step in path {
+        value = (step.migrate)(value)?;
+    }
+
+    let migrated_json = serde_json::to_string(&value)
+        .map_err(|e| SnapshotError::SerializationError(e.to_string()))?;
+
+    let state = decode_snapshot(&migrated_json)?;
+    try_validate_invariants(&state).map_err(SnapshotError::InvariantViolation)?;
+    Ok(state)
+}
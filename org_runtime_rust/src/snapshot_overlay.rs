@@ -0,0 +1,275 @@
+//! Layered Snapshot Overlays — `%include`/`%unset` composition.
+//!
+//! `decode_snapshot` only ever produces a complete `OrgState` from one
+//! JSON document. This module composes a final `OrgState` from a base
+//! snapshot plus an ordered list of overlay fragments — partial JSON
+//! documents that add or override roles and dependency edges by key,
+//! and can remove inherited entries with `%unset` or pull in further
+//! fragments with `%include`. Later overlays win; the composed result
+//! is validated against kernel invariants exactly once, at the end, so
+//! individual fragments need not be independently valid.
+//!
+//! Fragment shape:
+//! ```json
+//! {
+//!   "%include": ["shared/base.json"],
+//!   "%unset": { "roles": ["role_x"], "dependencies": ["role_a->role_b:operational"] },
+//!   "roles": { "role_id": { "id": "role_id", "name": "...", ... } },
+//!   "dependencies": [ { "from_role_id": "...", "to_role_id": "...", "dependency_type": "...", "critical": false } ],
+//!   "constraint_vector": { "capital": 60000 },
+//!   "scale_stage": "growth"
+//! }
+//! ```
+//! `%include` paths resolve relative to the directory of the fragment
+//! that names them.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::Value;
+
+use org_engine_replica::arithmetic::Scaled;
+use org_engine_replica::domain::{DependencyEdge, OrgState, Role};
+use org_engine_replica::invariants::try_validate_invariants;
+
+use crate::snapshot_codec::{
+    decode_snapshot, read_bounded, validate_path_component, DEFAULT_MAX_SNAPSHOT_BYTES,
+};
+
+/// An overlay composition failure.
+#[derive(Debug)]
+pub enum OverlayError {
+    Io(String),
+    Parse(String),
+    /// `%include` formed a cycle back to a fragment already being applied.
+    CycleDetected(PathBuf),
+    /// The composed `OrgState` violates a kernel invariant.
+    Invariant(String),
+}
+
+impl fmt::Display for OverlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverlayError::Io(msg) => write!(f, "io error: {}", msg),
+            OverlayError::Parse(msg) => write!(f, "parse error: {}", msg),
+            OverlayError::CycleDetected(path) => {
+                write!(f, "%include cycle detected at {}", path.display())
+            }
+            OverlayError::Invariant(msg) => write!(f, "invariant violation: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for OverlayError {}
+
+/// Deterministic identity for a `DependencyEdge`, used both to key
+/// override-by-key merges and to match `%unset` entries.
+fn edge_key(edge: &DependencyEdge) -> String {
+    format!(
+        "{}->{}:{}",
+        edge.from_role_id, edge.to_role_id, edge.dependency_type
+    )
+}
+
+/// Compose a final `OrgState` from `base` plus `overlay_paths`, applied
+/// in order — later overlays win. Invariants are validated once, after
+/// every overlay (and every file it `%include`s) has been applied.
+pub fn compose_snapshot(base: &OrgState, overlay_paths: &[PathBuf]) -> Result<OrgState, OverlayError> {
+    let mut state = base.clone();
+    for path in overlay_paths {
+        let mut visiting = HashSet::new();
+        apply_overlay_file(&mut state, path, &mut visiting)?;
+    }
+    try_validate_invariants(&state).map_err(OverlayError::Invariant)?;
+    Ok(state)
+}
+
+/// Decode a base snapshot file, then compose it with `overlay_paths`.
+pub fn compose_from_base_file(
+    base_path: &Path,
+    overlay_paths: &[PathBuf],
+) -> Result<OrgState, OverlayError> {
+    let base_json =
+        fs::read_to_string(base_path).map_err(|e| OverlayError::Io(e.to_string()))?;
+    let base = decode_snapshot(&base_json).map_err(|e| OverlayError::Parse(e.to_string()))?;
+    compose_snapshot(&base, overlay_paths)
+}
+
+/// Resolve one `%include` entry to a path, rejecting anything that
+/// could escape `parent`: an absolute `name`, any `..` component in
+/// `name`, or a final path component `validate_path_component` would
+/// reject (empty, `.`/`..`, reserved Windows device name, control
+/// characters). Fragment content is untrusted input — without this, a
+/// fragment could name `/etc/passwd` or `../../../../etc/shadow` and
+/// have it read straight into the composed state.
+fn resolve_include_path(parent: &Path, name: &str) -> Result<PathBuf, OverlayError> {
+    let candidate = Path::new(name);
+    if candidate.is_absolute() {
+        return Err(OverlayError::Io(format!(
+            "%include path must be relative: {:?}",
+            name
+        )));
+    }
+    if candidate
+        .components()
+        .any(|c| matches!(c, Component::ParentDir))
+    {
+        return Err(OverlayError::Io(format!(
+            "%include path must not contain '..': {:?}",
+            name
+        )));
+    }
+
+    let resolved = parent.join(candidate);
+    validate_path_component(&resolved).map_err(|e| OverlayError::Io(e.to_string()))?;
+    Ok(resolved)
+}
+
+/// Apply one fragment file (and, recursively, everything it
+/// `%include`s) to `state` in place. `visiting` tracks the current
+/// `%include` chain so a cycle is rejected instead of recursing forever.
+fn apply_overlay_file(
+    state: &mut OrgState,
+    path: &Path,
+    visiting: &mut HashSet<PathBuf>,
+) -> Result<(), OverlayError> {
+    let canon = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if !visiting.insert(canon.clone()) {
+        return Err(OverlayError::CycleDetected(canon));
+    }
+
+    let content =
+        read_bounded(path, DEFAULT_MAX_SNAPSHOT_BYTES).map_err(|e| OverlayError::Io(e.to_string()))?;
+    let doc: Value =
+        serde_json::from_str(&content).map_err(|e| OverlayError::Parse(e.to_string()))?;
+
+    // %include fragments apply first, so this fragment's own fields
+    // (including its %unset) still win over anything it includes.
+    if let Some(includes) = doc.get("%include").and_then(|v| v.as_array()) {
+        let parent = path.parent().unwrap_or_else(|| Path::new("."));
+        for include in includes {
+            let name = include
+                .as_str()
+                .ok_or_else(|| OverlayError::Parse("%include entries must be strings".to_string()))?;
+            let resolved = resolve_include_path(parent, name)?;
+            apply_overlay_file(state, &resolved, visiting)?;
+        }
+    }
+
+    apply_fragment_fields(state, &doc)?;
+
+    visiting.remove(&canon);
+    Ok(())
+}
+
+/// Apply one fragment's own `%unset`, role, dependency, and scalar
+/// overrides to `state` — not recursive, does not touch `%include`.
+fn apply_fragment_fields(state: &mut OrgState, doc: &Value) -> Result<(), OverlayError> {
+    if let Some(unset) = doc.get("%unset") {
+        if let Some(role_ids) = unset.get("roles").and_then(|v| v.as_array()) {
+            for role_id in role_ids.iter().filter_map(|v| v.as_str()) {
+                state.roles.remove(role_id);
+            }
+        }
+        if let Some(edge_ids) = unset.get("dependencies").and_then(|v| v.as_array()) {
+            let removed: HashSet<&str> = edge_ids.iter().filter_map(|v| v.as_str()).collect();
+            state
+                .dependencies
+                .retain(|edge| !removed.contains(edge_key(edge).as_str()));
+        }
+    }
+
+    if let Some(roles) = doc.get("roles").and_then(|v| v.as_object()) {
+        for (role_id, role_value) in roles {
+            let role: Role = serde_json::from_value(role_value.clone())
+                .map_err(|e| OverlayError::Parse(format!("role {:?}: {}", role_id, e)))?;
+            state.roles.insert(role_id.clone(), role);
+        }
+    }
+
+    if let Some(dependencies) = doc.get("dependencies").and_then(|v| v.as_array()) {
+        for dep_value in dependencies {
+            let edge: DependencyEdge = serde_json::from_value(dep_value.clone())
+                .map_err(|e| OverlayError::Parse(e.to_string()))?;
+            let key = edge_key(&edge);
+            state.dependencies.retain(|existing| edge_key(existing) != key);
+            state.dependencies.push(edge);
+        }
+    }
+
+    if let Some(cv) = doc.get("constraint_vector").and_then(|v| v.as_object()) {
+        if let Some(v) = cv.get("capital").and_then(|v| v.as_i64()) {
+            state.constraint_vector.capital = Scaled::from_raw(v);
+        }
+        if let Some(v) = cv.get("talent").and_then(|v| v.as_i64()) {
+            state.constraint_vector.talent = Scaled::from_raw(v);
+        }
+        if let Some(v) = cv.get("time").and_then(|v| v.as_i64()) {
+            state.constraint_vector.time = Scaled::from_raw(v);
+        }
+        if let Some(v) = cv.get("political_cost").and_then(|v| v.as_i64()) {
+            state.constraint_vector.political_cost = Scaled::from_raw(v);
+        }
+    }
+
+    if let Some(stage) = doc.get("scale_stage").and_then(|v| v.as_str()) {
+        state.scale_stage = stage.to_string();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join("org_snapshot_overlay_tests").join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn absolute_include_path_is_rejected() {
+        let dir = test_dir("absolute_include");
+        let fragment = dir.join("fragment.json");
+        std::fs::write(&fragment, r#"{"%include": ["/etc/passwd"]}"#).unwrap();
+
+        let mut state = OrgState::default();
+        let mut visiting = HashSet::new();
+        let err = apply_overlay_file(&mut state, &fragment, &mut visiting).unwrap_err();
+        assert!(matches!(err, OverlayError::Io(_)));
+    }
+
+    #[test]
+    fn parent_dir_include_path_is_rejected() {
+        let dir = test_dir("parent_dir_include");
+        let fragment = dir.join("fragment.json");
+        std::fs::write(&fragment, r#"{"%include": ["../../../../etc/shadow"]}"#).unwrap();
+
+        let mut state = OrgState::default();
+        let mut visiting = HashSet::new();
+        let err = apply_overlay_file(&mut state, &fragment, &mut visiting).unwrap_err();
+        assert!(matches!(err, OverlayError::Io(_)));
+    }
+
+    #[test]
+    fn well_formed_relative_include_still_applies() {
+        let dir = test_dir("relative_include");
+        std::fs::write(
+            dir.join("shared.json"),
+            r#"{"scale_stage": "structured"}"#,
+        )
+        .unwrap();
+        let fragment = dir.join("fragment.json");
+        std::fs::write(&fragment, r#"{"%include": ["shared.json"]}"#).unwrap();
+
+        let mut state = OrgState::default();
+        let mut visiting = HashSet::new();
+        apply_overlay_file(&mut state, &fragment, &mut visiting).unwrap();
+        assert_eq!(state.scale_stage, "structured");
+    }
+}
@@ -0,0 +1,293 @@
+//! Signed Snapshot Envelope — TUF-style threshold ed25519 verification.
+//!
+//! `Snapshot` carries only a self-hash, which catches corruption but not
+//! forgery: anyone who can write to the snapshot directory can replace
+//! the file with a different, internally-consistent snapshot. This
+//! module wraps a `Snapshot` in a `SignedSnapshot` envelope carrying one
+//! or more `{ key_id, sig }` entries, so snapshots can be exchanged over
+//! an untrusted channel and verified against a caller-supplied trusted
+//! key set and signature threshold — the same shape TUF uses for root
+//! metadata.
+//!
+//! `key_id` is content-derived (the SHA-256 of the public key's
+//! canonical JSON encoding), not assigned, so a signature always names
+//! the key that produced it rather than a caller-chosen label.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::snapshot::Snapshot;
+
+// ---------------------------------------------------------------------------
+// Keys
+// ---------------------------------------------------------------------------
+
+/// An ed25519 public key, trusted by content rather than by label.
+#[derive(Debug, Clone)]
+pub struct PublicKey {
+    pub verifying_key: VerifyingKey,
+}
+
+impl PublicKey {
+    pub fn from_signing_key(signing_key: &SigningKey) -> Self {
+        PublicKey {
+            verifying_key: signing_key.verifying_key(),
+        }
+    }
+}
+
+/// Canonical JSON encoding of a public key: `{"algorithm", "key_hex"}`
+/// in fixed field order, mirroring `hashing::canonical_serialize`'s
+/// explicit-`Map`-insertion style.
+fn canonical_public_key_bytes(pk: &PublicKey) -> Vec<u8> {
+    let mut obj = Map::new();
+    obj.insert("algorithm".to_string(), Value::String("ed25519".to_string()));
+    obj.insert(
+        "key_hex".to_string(),
+        Value::String(to_hex(pk.verifying_key.as_bytes())),
+    );
+    serde_json::to_vec(&Value::Object(obj)).expect("canonical public key serialization failed")
+}
+
+/// The content-derived identity of a public key: lowercase-hex SHA-256
+/// of its canonical JSON encoding.
+pub fn key_id(pk: &PublicKey) -> String {
+    let digest = Sha256::digest(canonical_public_key_bytes(pk));
+    to_hex(&digest)
+}
+
+// ---------------------------------------------------------------------------
+// Envelope
+// ---------------------------------------------------------------------------
+
+/// One signature over a `Snapshot`'s canonical bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SnapshotSignature {
+    /// Content-derived identity of the signing key (see `key_id`).
+    pub key_id: String,
+    /// Lowercase-hex ed25519 signature over `canonical_snapshot_bytes`.
+    pub sig: String,
+}
+
+/// A `Snapshot` plus one or more signatures over its canonical bytes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SignedSnapshot {
+    pub snapshot: Snapshot,
+    pub signatures: Vec<SnapshotSignature>,
+}
+
+/// Canonical serialization of a `Snapshot`'s fields, in fixed order —
+/// the bytes that are actually signed and verified. Distinct from
+/// `hashing::canonical_serialize`, which serializes the `OrgState`
+/// *inside* `canonical_json`; this is the outer envelope's own identity.
+fn canonical_snapshot_bytes(snap: &Snapshot) -> Vec<u8> {
+    let mut obj = Map::new();
+    obj.insert("sequence".to_string(), Value::Number(snap.sequence.into()));
+    obj.insert(
+        "canonical_json".to_string(),
+        Value::String(snap.canonical_json.clone()),
+    );
+    let mut hashes = Map::new();
+    for (algo, digest) in &snap.hashes {
+        hashes.insert(algo.as_str().to_string(), Value::String(digest.clone()));
+    }
+    obj.insert("hashes".to_string(), Value::Object(hashes));
+    obj.insert(
+        "kernel_version".to_string(),
+        Value::Number((snap.kernel_version as u64).into()),
+    );
+    serde_json::to_vec(&Value::Object(obj)).expect("canonical snapshot serialization failed")
+}
+
+// ---------------------------------------------------------------------------
+// Sign / verify
+// ---------------------------------------------------------------------------
+
+/// Produce a `SignedSnapshot` with a single signature from `signing_key`.
+///
+/// Call repeatedly and merge `signatures` to build a multi-signer
+/// envelope (e.g. for a threshold above 1).
+pub fn sign_snapshot(snapshot: &Snapshot, signing_key: &SigningKey) -> SignedSnapshot {
+    let bytes = canonical_snapshot_bytes(snapshot);
+    let sig: Signature = signing_key.sign(&bytes);
+    let pk = PublicKey::from_signing_key(signing_key);
+
+    SignedSnapshot {
+        snapshot: snapshot.clone(),
+        signatures: vec![SnapshotSignature {
+            key_id: key_id(&pk),
+            sig: to_hex(&sig.to_bytes()),
+        }],
+    }
+}
+
+/// A signature/threshold verification failure.
+#[derive(Debug)]
+pub enum SignatureError {
+    /// Fewer distinct trusted keys produced a valid signature than
+    /// `threshold` required. Malformed hex / wrong-length / crypto-invalid
+    /// signature entries are all just skipped (see `verify_signed_snapshot`)
+    /// and fold into this count rather than surfacing their own error —
+    /// a tampered individual entry must not short-circuit the rest of
+    /// the list.
+    ThresholdNotMet { got: usize, required: usize },
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::ThresholdNotMet { got, required } => write!(
+                f,
+                "signature threshold not met: {} of {} required distinct valid signatures",
+                got, required
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+/// Verify a `SignedSnapshot` against a trusted key set and threshold.
+///
+/// Each signature's `key_id` is recomputed from the trusted keys (never
+/// taken at face value); signatures from a key not in `trusted`, or that
+/// fail to verify, are ignored. Only *distinct* valid `key_id`s count
+/// toward `threshold`, so the same key signing twice cannot satisfy a
+/// threshold above 1.
+pub fn verify_signed_snapshot(
+    signed: &SignedSnapshot,
+    trusted: &[PublicKey],
+    threshold: usize,
+) -> Result<(), SignatureError> {
+    let bytes = canonical_snapshot_bytes(&signed.snapshot);
+    let mut valid_key_ids: BTreeSet<String> = BTreeSet::new();
+
+    for entry in &signed.signatures {
+        let matching_key = trusted.iter().find(|pk| key_id(pk) == entry.key_id);
+        let Some(matching_key) = matching_key else {
+            continue;
+        };
+
+        let sig_bytes = match hex_decode(&entry.sig) {
+            Some(b) if b.len() == 64 => b,
+            // Malformed hex / wrong length is just an invalid signature,
+            // not a reason to abort checking the rest of the list — an
+            // attacker who can tamper with `signatures` must not be able
+            // to force rejection of an otherwise-thresholded snapshot by
+            // injecting one throwaway garbage entry under a trusted
+            // key_id.
+            _ => continue,
+        };
+        let mut sig_array = [0u8; 64];
+        sig_array.copy_from_slice(&sig_bytes);
+        let sig = Signature::from_bytes(&sig_array);
+
+        if matching_key.verifying_key.verify(&bytes, &sig).is_ok() {
+            valid_key_ids.insert(entry.key_id.clone());
+        }
+    }
+
+    if valid_key_ids.len() >= threshold {
+        Ok(())
+    } else {
+        Err(SignatureError::ThresholdNotMet {
+            got: valid_key_ids.len(),
+            required: threshold,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hex helpers (no external hex crate is vendored in this tree)
+// ---------------------------------------------------------------------------
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::snapshot::HashAlgorithm;
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn sample_snapshot() -> Snapshot {
+        let mut hashes = BTreeMap::new();
+        hashes.insert(HashAlgorithm::Sha256, "a".repeat(64));
+        Snapshot {
+            sequence: 7,
+            canonical_json: "{}".to_string(),
+            hashes,
+            kernel_version: 1,
+        }
+    }
+
+    #[test]
+    fn signed_snapshot_round_trips() {
+        let key = signing_key(1);
+        let snap = sample_snapshot();
+        let signed = sign_snapshot(&snap, &key);
+        let trusted = vec![PublicKey::from_signing_key(&key)];
+
+        assert!(verify_signed_snapshot(&signed, &trusted, 1).is_ok());
+    }
+
+    #[test]
+    fn threshold_not_met_without_enough_distinct_signers() {
+        let key = signing_key(1);
+        let snap = sample_snapshot();
+        let signed = sign_snapshot(&snap, &key);
+        let trusted = vec![PublicKey::from_signing_key(&key), PublicKey::from_signing_key(&signing_key(2))];
+
+        let err = verify_signed_snapshot(&signed, &trusted, 2).unwrap_err();
+        assert!(matches!(
+            err,
+            SignatureError::ThresholdNotMet { got: 1, required: 2 }
+        ));
+    }
+
+    /// A garbage `sig` under a trusted `key_id` must not short-circuit
+    /// verification of the other, genuinely valid signatures in the
+    /// list — an attacker tampering with `signatures` must not be able
+    /// to force rejection of an otherwise-thresholded snapshot this way.
+    #[test]
+    fn malformed_signature_entry_is_skipped_not_fatal() {
+        let key1 = signing_key(1);
+        let key2 = signing_key(2);
+        let snap = sample_snapshot();
+        let mut signed = sign_snapshot(&snap, &key1);
+        signed.signatures.extend(sign_snapshot(&snap, &key2).signatures);
+
+        // Inject a throwaway garbage-hex entry under key1's trusted id.
+        signed.signatures.push(SnapshotSignature {
+            key_id: key_id(&PublicKey::from_signing_key(&key1)),
+            sig: "not-valid-hex".to_string(),
+        });
+
+        let trusted = vec![PublicKey::from_signing_key(&key1), PublicKey::from_signing_key(&key2)];
+
+        assert!(verify_signed_snapshot(&signed, &trusted, 2).is_ok());
+    }
+}
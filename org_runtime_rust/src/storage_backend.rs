@@ -0,0 +1,48 @@
+//! Pluggable storage backend for the event log.
+//!
+//! `EventStore` (event_store.rs) always reads the entire log front to
+//! back to learn `last_sequence`, and always returns the whole log from
+//! `load_all_events` — both O(total bytes), which stops scaling once a
+//! log grows large. `StorageBackend` is the seam that lets a caller swap
+//! in a backend with O(1) sequence lookup and O(range) ranged reads
+//! (e.g. an LSM-backed store) without `Session`/`replay` changing.
+
+use std::io;
+
+use crate::proto_types::ProtoEventEnvelope;
+
+/// An append-only, sequence-ordered event log.
+pub trait StorageBackend {
+    /// Append one event. Implementations must validate strict sequence
+    /// ordering and fsync (or equivalent durability guarantee) before
+    /// returning `Ok`.
+    fn append(&mut self, event: &ProtoEventEnvelope) -> io::Result<()>;
+
+    /// The highest sequence number durably stored, or 0 if empty.
+    fn last_sequence(&self) -> u64;
+
+    /// Load every event with `from_seq <= sequence <= to_seq`, in
+    /// ascending sequence order.
+    fn load_range(&self, from_seq: u64, to_seq: u64) -> io::Result<Vec<ProtoEventEnvelope>>;
+
+    /// Load every event in the log, in ascending sequence order.
+    fn load_all(&self) -> io::Result<Vec<ProtoEventEnvelope>>;
+
+    /// Recompute and check whatever tamper-evidence this backend keeps
+    /// (e.g. `EventStore`'s hash-chain sidecar), returning `Err`
+    /// describing the first divergence found. Backends with no such
+    /// mechanism (e.g. a plain LSM-backed store) report `Ok(())` —
+    /// callers that need a hard integrity guarantee should check which
+    /// backend they're on rather than rely on this alone.
+    fn verify_integrity(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Every intermediate hash-chain head, one per event in ascending
+    /// sequence order, if this backend tracks one. `Session::verify_chain`
+    /// uses this to check signatures recorded over each head as it was
+    /// produced; `None` for backends that don't track a chain.
+    fn chain_heads(&self) -> io::Result<Option<Vec<[u8; 32]>>> {
+        Ok(None)
+    }
+}
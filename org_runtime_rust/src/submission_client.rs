@@ -0,0 +1,250 @@
+//! Sync/async submission clients over a `Session`.
+//!
+//! `OrgEngine::apply_event` is local-only and panics on a sequence gap —
+//! fine for a single in-process producer, but a networked producer can
+//! legitimately race another writer and see its guess at the next
+//! sequence go stale between "read `last_sequence`" and "submit". This
+//! module is the seam that absorbs that race: `SyncClient::submit_and_confirm`
+//! stamps an event with the sequence/schema it currently believes is
+//! correct, submits it through `Session::try_apply_event` (so a rejection
+//! comes back as `Err`, not a panic), and on a `SequenceViolation`
+//! re-stamps against the now-current sequence and retries, bounded so a
+//! persistently diverging producer fails loudly instead of spinning
+//! forever. `AsyncClient::submit` is the fire-and-forget twin for
+//! producers that don't need (or want to block on) confirmation.
+//!
+//! `InProcessClient` is the only implementation today — it wraps a
+//! `Session` directly, the same way `EventStore`/`StorageBackend` ship one
+//! concrete backend behind a trait seam for a future networked one to
+//! slot in alongside.
+
+use std::fmt;
+
+use org_engine_replica::domain::OrgState;
+use org_engine_replica::error::KernelError;
+use org_engine_replica::events::{EventEnvelope, SCHEMA_VERSION};
+
+use crate::session::Session;
+
+/// Builds the event to submit once the next `sequence`/`schema_version`
+/// are known — the same information a networked producer would learn by
+/// querying the server before constructing its request.
+pub type EventBuilder<'a> = &'a dyn Fn(u64, u32) -> EventEnvelope;
+
+/// Why `SyncClient::submit_and_confirm` gave up.
+#[derive(Debug, Clone)]
+pub enum SubmissionError {
+    /// The kernel rejected the event for a reason a re-stamped retry
+    /// can't fix (e.g. an unknown event type, a missing role).
+    Rejected(KernelError),
+    /// Every retry still raced another producer's `SequenceViolation`.
+    RetriesExhausted { attempts: u32, last_error: KernelError },
+}
+
+impl fmt::Display for SubmissionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionError::Rejected(e) => write!(f, "submission rejected: {}", e),
+            SubmissionError::RetriesExhausted {
+                attempts,
+                last_error,
+            } => write!(
+                f,
+                "submission gave up after {} attempt(s), last error: {}",
+                attempts, last_error
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SubmissionError {}
+
+/// Submit one event and block for confirmation, re-stamping and retrying
+/// when the sequence the client guessed has gone stale.
+pub trait SyncClient {
+    /// Sequence this client currently believes is confirmed.
+    fn last_sequence(&self) -> u64;
+
+    /// Stamp `build(next_sequence, schema_version)` and submit it. On a
+    /// `KernelError::SequenceViolation`, refetch `last_sequence` and retry
+    /// with a freshly stamped event, up to `max_retries` times.
+    fn submit_and_confirm(
+        &mut self,
+        build: EventBuilder,
+        max_retries: u32,
+    ) -> Result<OrgState, SubmissionError>;
+}
+
+/// Submit one event without waiting for confirmation.
+pub trait AsyncClient {
+    /// Stamp `build(next_sequence, schema_version)` and submit it,
+    /// discarding the result — a rejection is silently dropped, same as
+    /// any other fire-and-forget transport.
+    fn submit(&mut self, build: EventBuilder);
+}
+
+/// In-process `SyncClient`/`AsyncClient` wrapping a `Session` directly —
+/// the local stand-in for what a networked client would do over a
+/// transport.
+pub struct InProcessClient {
+    session: Session,
+    /// Last sequence this client saw confirmed; refetched from the
+    /// session only after a `SequenceViolation`.
+    believed_sequence: u64,
+}
+
+impl InProcessClient {
+    pub fn new(session: Session) -> Self {
+        let believed_sequence = session.current_sequence();
+        Self { session, believed_sequence }
+    }
+
+    /// Unwrap back to the underlying `Session`.
+    pub fn into_session(self) -> Session {
+        self.session
+    }
+}
+
+impl SyncClient for InProcessClient {
+    fn last_sequence(&self) -> u64 {
+        self.believed_sequence
+    }
+
+    fn submit_and_confirm(
+        &mut self,
+        build: EventBuilder,
+        max_retries: u32,
+    ) -> Result<OrgState, SubmissionError> {
+        let mut last_error = None;
+
+        for attempt in 0..=max_retries {
+            let next_sequence = self.believed_sequence + 1;
+            let event = build(next_sequence, SCHEMA_VERSION);
+
+            match self.session.try_apply_event(&event) {
+                Ok((state, _)) => {
+                    self.believed_sequence = event.sequence;
+                    return Ok(state);
+                }
+                Err(KernelError::SequenceViolation { .. }) => {
+                    self.believed_sequence = self.session.current_sequence();
+                    last_error = Some(KernelError::SequenceViolation {
+                        expected: self.believed_sequence + 1,
+                        got: event.sequence,
+                    });
+                    let _ = attempt;
+                    continue;
+                }
+                Err(e) => return Err(SubmissionError::Rejected(e)),
+            }
+        }
+
+        Err(SubmissionError::RetriesExhausted {
+            attempts: max_retries + 1,
+            last_error: last_error.expect("loop only exits here after at least one retry"),
+        })
+    }
+}
+
+impl AsyncClient for InProcessClient {
+    fn submit(&mut self, build: EventBuilder) {
+        let next_sequence = self.session.current_sequence() + 1;
+        let event = build(next_sequence, SCHEMA_VERSION);
+        let _ = self.session.try_apply_event(&event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn test_session(name: &str) -> Session {
+        let dir = std::env::temp_dir().join("org_submission_client_tests").join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut session = Session::new(&dir, "s1", 0).expect("session setup failed");
+        session
+            .try_apply_event(&EventEnvelope {
+                event_type: "initialize_constants".to_string(),
+                sequence: 1,
+                timestamp: String::new(),
+                logical_time: 0,
+                payload: serde_json::json!({}),
+                schema_version: SCHEMA_VERSION,
+            })
+            .expect("initialize_constants must succeed");
+        session
+    }
+
+    fn add_role_event(sequence: u64, schema_version: u32, role_id: &str) -> EventEnvelope {
+        EventEnvelope {
+            event_type: "add_role".to_string(),
+            sequence,
+            timestamp: String::new(),
+            logical_time: sequence,
+            payload: serde_json::json!({"id": role_id, "name": "n", "purpose": "p"}),
+            schema_version,
+        }
+    }
+
+    #[test]
+    fn retries_after_one_sequence_violation_then_succeeds() {
+        let mut client = InProcessClient::new(test_session("retry_succeeds"));
+        // Steal sequence 2 out from under the client, so its first guess
+        // is stale and must be re-stamped.
+        client
+            .session
+            .try_apply_event(&add_role_event(2, SCHEMA_VERSION, "stolen"))
+            .expect("stolen event must succeed");
+
+        let calls = Cell::new(0u32);
+        let build: EventBuilder = &|next_sequence, schema_version| {
+            calls.set(calls.get() + 1);
+            add_role_event(next_sequence, schema_version, "r1")
+        };
+
+        let result = client.submit_and_confirm(build, 3);
+        assert!(result.is_ok(), "expected retry to succeed: {:?}", result.err());
+        assert_eq!(calls.get(), 2, "should have re-stamped exactly once");
+        assert_eq!(client.last_sequence(), 3);
+    }
+
+    #[test]
+    fn exhausting_retries_returns_retries_exhausted_without_panicking() {
+        let mut client = InProcessClient::new(test_session("retries_exhausted"));
+
+        // Always stamp a sequence far in the future — every attempt
+        // collides with a SequenceViolation, so retries must run out.
+        let build: EventBuilder = &|_next_sequence, schema_version| {
+            add_role_event(999, schema_version, "r1")
+        };
+
+        let result = client.submit_and_confirm(build, 2);
+        match result {
+            Err(SubmissionError::RetriesExhausted { attempts, last_error }) => {
+                assert_eq!(attempts, 3);
+                assert!(matches!(last_error, KernelError::SequenceViolation { .. }));
+            }
+            other => panic!("expected RetriesExhausted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn non_sequence_rejection_is_not_retried() {
+        let mut client = InProcessClient::new(test_session("non_sequence_rejection"));
+
+        let build: EventBuilder =
+            &|next_sequence, schema_version| EventEnvelope {
+                event_type: "remove_role".to_string(),
+                sequence: next_sequence,
+                timestamp: String::new(),
+                logical_time: next_sequence,
+                payload: serde_json::json!({"role_id": "does_not_exist"}),
+                schema_version,
+            };
+
+        let result = client.submit_and_confirm(build, 5);
+        assert!(matches!(result, Err(SubmissionError::Rejected(KernelError::MissingRole(_)))));
+    }
+}
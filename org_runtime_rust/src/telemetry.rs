@@ -0,0 +1,172 @@
+#![cfg(feature = "telemetry")]
+//! OrgEngine v1.1 Rust Runtime — Telemetry (optional side channel)
+//!
+//! Mirrors `org_engine_replica::telemetry`'s span/metric pattern one
+//! layer up, over `Session::apply_event`/`try_apply_event`,
+//! `Session::replay_full`, `snapshot::save_snapshot`, and
+//! `drift::verify_determinism`. This module only observes — it never
+//! writes session/engine state, so determinism is unaffected
+//! regardless of whether the `telemetry` feature is enabled.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use opentelemetry::global;
+use opentelemetry::metrics::{Counter, Gauge, Histogram, Meter};
+use tracing::info_span;
+
+use org_engine_replica::domain::OrgState;
+use org_engine_replica::graph::compute_structural_density;
+
+struct Instruments {
+    events_applied: Counter<u64>,
+    invariant_rejections: Counter<u64>,
+    apply_latency_ms: Histogram<f64>,
+    structural_debt: Gauge<u64>,
+    structural_density: Gauge<u64>,
+    active_role_count: Gauge<u64>,
+    verify_determinism_mismatches: Counter<u64>,
+}
+
+fn instruments() -> &'static Instruments {
+    static INSTRUMENTS: OnceLock<Instruments> = OnceLock::new();
+    INSTRUMENTS.get_or_init(|| {
+        let meter: Meter = global::meter("org_runtime_rust");
+        Instruments {
+            events_applied: meter.u64_counter("org_runtime.events_applied_total").build(),
+            invariant_rejections: meter
+                .u64_counter("org_runtime.invariant_rejections_total")
+                .build(),
+            apply_latency_ms: meter.f64_histogram("org_runtime.apply_latency_ms").build(),
+            structural_debt: meter.u64_gauge("org_runtime.structural_debt").build(),
+            structural_density: meter.u64_gauge("org_runtime.structural_density").build(),
+            active_role_count: meter.u64_gauge("org_runtime.active_role_count").build(),
+            verify_determinism_mismatches: meter
+                .u64_counter("org_runtime.verify_determinism_mismatches_total")
+                .build(),
+        }
+    })
+}
+
+/// Wrap a panicking `Session::apply_event` call with a span
+/// (`session_id`, `sequence`, `event_type`) and latency/count metrics.
+/// If `run` panics — an invariant rejection surfaced by the kernel — the
+/// rejection counter is incremented before the panic resumes unwinding,
+/// same shape as `org_engine_replica::telemetry::traced_apply_event`.
+pub fn traced_apply_event<F, T>(
+    session_id: &str,
+    sequence: u64,
+    event_type: &str,
+    run: F,
+) -> T
+where
+    F: FnOnce() -> T,
+{
+    let span = info_span!(
+        "org_runtime.apply_event",
+        session_id = %session_id,
+        sequence = sequence,
+        event_type = %event_type,
+    );
+    let _guard = span.enter();
+
+    let start = Instant::now();
+    match panic::catch_unwind(AssertUnwindSafe(run)) {
+        Ok(value) => {
+            let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+            instruments().events_applied.add(1, &[]);
+            instruments().apply_latency_ms.record(elapsed_ms, &[]);
+            value
+        }
+        Err(payload) => {
+            instruments().invariant_rejections.add(1, &[]);
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+/// Wrap the non-panicking `Session::try_apply_event` call with the same
+/// span and latency metric as `traced_apply_event`. `run` returns
+/// `Result`-shaped outcomes rather than panicking, so there is nothing
+/// to catch here — a rejected event is counted on the rejection counter
+/// instead of as applied.
+pub fn traced_try_apply_event<F, T, E>(
+    session_id: &str,
+    sequence: u64,
+    event_type: &str,
+    run: F,
+) -> Result<T, E>
+where
+    F: FnOnce() -> Result<T, E>,
+{
+    let span = info_span!(
+        "org_runtime.apply_event",
+        session_id = %session_id,
+        sequence = sequence,
+        event_type = %event_type,
+    );
+    let _guard = span.enter();
+
+    let start = Instant::now();
+    let result = run();
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    match &result {
+        Ok(_) => {
+            instruments().events_applied.add(1, &[]);
+            instruments().apply_latency_ms.record(elapsed_ms, &[]);
+        }
+        Err(_) => {
+            instruments().invariant_rejections.add(1, &[]);
+        }
+    }
+
+    result
+}
+
+/// Record the post-apply `structural_debt`, `structural_density`, and
+/// active-role-count gauges. Called after every successful apply (and
+/// after `replay_full`) so the gauges track the state that resulted.
+/// All three quantities are integers — recorded as `u64` (negative
+/// values clamped to 0), no float conversion.
+pub fn record_state_gauges(state: &OrgState) {
+    let i = instruments();
+    i.structural_debt.record(state.structural_debt.max(0) as u64, &[]);
+    i.structural_density
+        .record(compute_structural_density(state).max(0) as u64, &[]);
+    let active_roles = state.roles.values().filter(|r| r.active).count() as u64;
+    i.active_role_count.record(active_roles, &[]);
+}
+
+/// Wrap `Session::replay_full` with a span carrying `session_id`.
+pub fn traced_replay<F, T>(session_id: &str, run: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let span = info_span!("org_runtime.replay_full", session_id = %session_id);
+    let _guard = span.enter();
+    run()
+}
+
+/// Wrap `snapshot::save_snapshot` with a span carrying `session_id` and
+/// `sequence`.
+pub fn traced_snapshot<F, T>(session_id: &str, sequence: u64, run: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let span = info_span!(
+        "org_runtime.snapshot",
+        session_id = %session_id,
+        sequence = sequence,
+    );
+    let _guard = span.enter();
+    run()
+}
+
+/// Increment the `verify_determinism` mismatch counter. Called by
+/// `drift::verify_determinism` before it panics on a divergence, so the
+/// mismatch is observable even though the process then aborts.
+pub fn record_determinism_mismatch() {
+    instruments().verify_determinism_mismatches.add(1, &[]);
+}
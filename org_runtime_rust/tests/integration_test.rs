@@ -238,3 +238,47 @@ fn snapshot_replay_parity() {
         .expect("should find latest");
     assert_eq!(latest.hash, hash);
 }
+
+// ─────────────────────────────────────────────────────────────
+// Test 7: compaction_replay_parity
+// ─────────────────────────────────────────────────────────────
+
+#[test]
+fn compaction_replay_parity() {
+    let dir = temp_dir("compaction_parity");
+    let events = load_golden_events();
+
+    // Append the full golden log.
+    let log_path = dir.join("events.log");
+    {
+        let mut store = EventStore::open(&log_path).expect("open store");
+        for evt in &events {
+            let proto = kernel_to_proto(evt);
+            store.append_event(&proto).expect("append event");
+        }
+    }
+
+    // Snapshot the state partway through (sequence 10).
+    let cut = 10u64;
+    let (cut_state, _) = replay::rebuild_state(&events[..cut as usize]);
+    let snap_dir = dir.join("snapshots");
+    snapshot::save_snapshot(&snap_dir, cut, &cut_state).expect("save snapshot");
+    let snap = snapshot::load_snapshot(&snap_dir, cut)
+        .expect("load snapshot")
+        .expect("snapshot should exist");
+
+    // Compact the log, pruning every frame with sequence <= cut.
+    {
+        let mut store = EventStore::open(&log_path).expect("reopen store");
+        store.compact_to(&snap).expect("compact_to");
+    }
+
+    // Bootstrap: load the latest snapshot, then replay only the tail.
+    let store = EventStore::open(&log_path).expect("reopen compacted store");
+    let (_, hash) = replay::bootstrap(&store, &snap_dir).expect("bootstrap");
+
+    assert_eq!(
+        hash, GOLDEN_HASH,
+        "Compacted + bootstrapped replay should still match the golden hash"
+    );
+}
@@ -0,0 +1,122 @@
+//! Cross-target determinism harness — native vs. wasm32-unknown-unknown.
+//!
+//! `drift::verify_determinism` replays the same events twice in one
+//! process and catches non-determinism within a single build, but not
+//! across toolchains/architectures — a `BTreeMap` iteration order or
+//! integer-width assumption that happens to hold on the host might not
+//! on wasm32. This test builds the `wasm_determinism` guest crate for
+//! `wasm32-unknown-unknown` with debug assertions and overflow checks
+//! enabled (see its `Cargo.toml`), runs the golden event stream through
+//! it inside a `wasmtime` instance, and asserts the resulting hash is
+//! byte-identical to the same stream replayed natively.
+//!
+//! Ignored by default: it shells out to `cargo build --target
+//! wasm32-unknown-unknown`, which needs that target installed and (on a
+//! clean checkout) network access to fetch `wasmtime`. Run explicitly
+//! with `cargo test --test wasm_determinism -- --ignored`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use org_engine_replica::events::EventEnvelope;
+use org_runtime_rust::replay::rebuild_hash;
+
+fn golden_events_json() -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("org_engine_replica")
+        .join("tests")
+        .join("golden")
+        .join("events.json");
+    fs::read_to_string(&path).expect("failed to read golden events.json")
+}
+
+fn build_wasm_guest() -> PathBuf {
+    let guest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("wasm_determinism");
+
+    let status = Command::new("cargo")
+        .arg("build")
+        .arg("--target")
+        .arg("wasm32-unknown-unknown")
+        .arg("--manifest-path")
+        .arg(guest_dir.join("Cargo.toml"))
+        .status()
+        .expect("failed to invoke cargo for the wasm_determinism guest");
+    assert!(status.success(), "wasm32-unknown-unknown build of wasm_determinism failed");
+
+    guest_dir
+        .join("target")
+        .join("wasm32-unknown-unknown")
+        .join("debug")
+        .join("wasm_determinism.wasm")
+}
+
+/// Run the guest's `rebuild_hash_json` export over `events_json` inside
+/// a fresh `wasmtime` instance and return the hash it produced.
+fn run_wasm_guest(wasm_path: &Path, events_json: &str) -> String {
+    use wasmtime::{Engine, Instance, Module, Store};
+
+    let engine = Engine::default();
+    let module = Module::from_file(&engine, wasm_path).expect("failed to load wasm module");
+    let mut store = Store::new(&engine, ());
+    let instance =
+        Instance::new(&mut store, &module, &[]).expect("failed to instantiate wasm module");
+
+    let memory = instance
+        .get_memory(&mut store, "memory")
+        .expect("guest did not export linear memory");
+    let alloc = instance
+        .get_typed_func::<u32, u32>(&mut store, "alloc")
+        .expect("guest did not export alloc");
+    let rebuild_hash_json = instance
+        .get_typed_func::<(u32, u32), u32>(&mut store, "rebuild_hash_json")
+        .expect("guest did not export rebuild_hash_json");
+
+    let bytes = events_json.as_bytes();
+    let ptr = alloc
+        .call(&mut store, bytes.len() as u32)
+        .expect("guest alloc call failed");
+    memory
+        .write(&mut store, ptr as usize, bytes)
+        .expect("failed to write event JSON into guest memory");
+
+    let result_ptr = rebuild_hash_json
+        .call(&mut store, (ptr, bytes.len() as u32))
+        .expect("guest rebuild_hash_json call failed");
+
+    read_nul_terminated(&memory, &store, result_ptr)
+}
+
+fn read_nul_terminated(memory: &wasmtime::Memory, store: &wasmtime::Store<()>, ptr: u32) -> String {
+    let data = memory.data(store);
+    let start = ptr as usize;
+    let end = data[start..]
+        .iter()
+        .position(|&b| b == 0)
+        .map(|offset| start + offset)
+        .expect("guest string was not NUL-terminated");
+    String::from_utf8(data[start..end].to_vec()).expect("guest string was not valid UTF-8")
+}
+
+#[test]
+#[ignore]
+fn wasm_and_native_hashes_match() {
+    let events_json = golden_events_json();
+
+    let arr: Vec<serde_json::Value> =
+        serde_json::from_str(&events_json).expect("failed to parse golden events.json");
+    let events: Vec<EventEnvelope> = arr.iter().map(EventEnvelope::from_value).collect();
+    let native_hash = rebuild_hash(&events);
+
+    let wasm_path = build_wasm_guest();
+    let wasm_hash = run_wasm_guest(&wasm_path, &events_json);
+
+    assert_eq!(
+        native_hash, wasm_hash,
+        "native and wasm32 replays diverged — the kernel's \"deterministic by \
+         the kernel's guarantee\" claim does not hold across targets"
+    );
+}
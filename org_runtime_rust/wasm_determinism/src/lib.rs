@@ -0,0 +1,46 @@
+//! WASM guest for the cross-target determinism harness.
+//!
+//! Exposes just enough C-ABI surface for
+//! `org_runtime_rust/tests/wasm_determinism.rs` (the host side, running
+//! under `wasmtime`) to hand over a golden event-stream JSON string and
+//! get back the canonical hash `rebuild_hash` produces on wasm32 — so it
+//! can be compared byte-for-byte against the same replay run natively.
+//! Nothing here runs in production; it exists only to make the kernel's
+//! "deterministic by the kernel's guarantee" claim a tested invariant
+//! across architectures, not just within one process.
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use org_engine_replica::events::EventEnvelope;
+use org_runtime_rust::replay::rebuild_hash;
+
+/// Allocate `len` bytes of guest linear memory and return a pointer the
+/// host writes the event-stream JSON into before calling
+/// `rebuild_hash_json`, which takes the buffer back and frees it. The
+/// guest instance is torn down after one call, so the returned hash
+/// string is never freed.
+#[no_mangle]
+pub extern "C" fn alloc(len: u32) -> u32 {
+    let mut buf: Vec<u8> = Vec::with_capacity(len as usize);
+    let ptr = buf.as_mut_ptr();
+    std::mem::forget(buf);
+    ptr as u32
+}
+
+/// Parse `len` bytes of event-stream JSON at `ptr` (as written by the
+/// host after `alloc`), replay it through the kernel, and return a
+/// pointer to a leaked, NUL-terminated string holding the canonical
+/// hash.
+#[no_mangle]
+pub extern "C" fn rebuild_hash_json(ptr: u32, len: u32) -> u32 {
+    let bytes = unsafe { Vec::from_raw_parts(ptr as *mut u8, len as usize, len as usize) };
+    let json = String::from_utf8(bytes).expect("event JSON is not valid UTF-8");
+    let arr: Vec<serde_json::Value> =
+        serde_json::from_str(&json).expect("failed to parse event JSON");
+    let events: Vec<EventEnvelope> = arr.iter().map(EventEnvelope::from_value).collect();
+
+    let hash = rebuild_hash(&events);
+    let c_string = CString::new(hash).expect("canonical hash is never NUL");
+    c_string.into_raw() as u32
+}